@@ -0,0 +1,94 @@
+use std::fmt;
+use toml::{Table, Value};
+
+/// Accepted answers for the bundled input of a day, as recorded in its `answers.toml`
+#[derive(Debug, Default)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn parse(answers_text: &str) -> Result<Self, toml::de::Error> {
+        let table: Table = answers_text.parse()?;
+        let answer = |key: &str| match table.get(key) {
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(value) => Some(value.to_string()),
+            None => None,
+        };
+        Ok(Answers {
+            part1: answer("part1"),
+            part2: answer("part2"),
+        })
+    }
+
+    pub fn check(&self, part: u32, answer: &str) -> Status {
+        let expected: Option<&String> = match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        };
+        match expected {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+            },
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part1 = 142\npart2 = \"abc\"").unwrap();
+        assert_eq!(Status::Pass, answers.check(1, "142"));
+        assert_eq!(
+            Status::Fail {
+                expected: "142".to_string()
+            },
+            answers.check(1, "143")
+        );
+        assert_eq!(Status::Pass, answers.check(2, "abc"));
+        assert_eq!(Status::Unknown, Answers::parse("").unwrap().check(1, "142"));
+    }
+
+    #[test]
+    fn test_all_days_match_recorded_answers() {
+        for day in days::ALL {
+            let answers = Answers::parse(day.answers).unwrap();
+            for part in [1, 2] {
+                let answer: String = day.solution.run(part, day.input_text).unwrap();
+                assert_eq!(
+                    Status::Pass,
+                    answers.check(part, &answer),
+                    "{} day {} part {}",
+                    day.year,
+                    day.day,
+                    part
+                );
+            }
+        }
+    }
+}
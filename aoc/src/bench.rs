@@ -0,0 +1,106 @@
+use crate::history::{self, History, Record};
+use crate::{days, flag_value};
+use aoc_common::bench::{BenchConfig, Stats};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Default slowdown of the median, in percent, above which a phase is flagged as a regression
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Benchmarks every day (optionally only for one year or day) over its bundled input and prints
+/// a summary table with one row per phase.
+///
+/// Results are stored in a history file for the current commit, and compared with the latest
+/// results of a previous commit. Returns the number of phases whose median got slower by more
+/// than the threshold.
+pub fn run(args: &[String]) -> Result<usize, String> {
+    let mut config = BenchConfig::default();
+    let mut threshold: f64 = DEFAULT_THRESHOLD;
+    let mut history_path: Option<PathBuf> = Some(history::default_path());
+    let mut filters: Vec<u32> = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--samples" => config.samples = flag_value(arg, args.next())?,
+            "--warmup" => config.warmup = flag_value(arg, args.next())?,
+            "--threshold" => threshold = flag_value(arg, args.next())?,
+            "--history" => {
+                history_path = Some(PathBuf::from(
+                    args.next().ok_or("--history expects a path")?,
+                ))
+            }
+            "--no-history" => history_path = None,
+            _ if filters.len() < 2 => filters.push(
+                arg.parse()
+                    .map_err(|_| format!("Expected a year or a day, got {}", arg))?,
+            ),
+            _ => return Err(format!("Unexpected argument {}", arg)),
+        }
+    }
+
+    let commit: String = history::current_commit();
+    let mut history: History = match &history_path {
+        Some(path) => History::load(path).map_err(|e| e.to_string())?,
+        None => History::default(),
+    };
+
+    let mut regressions: usize = 0;
+    println!(
+        "{:>4} {:>3} {:<6} {:>10} {:>10} {:>10} {:>9}",
+        "Year", "Day", "Phase", "Min", "Median", "P95", "Change"
+    );
+    for day in days::filter(&filters) {
+        let all_stats: [Stats; 3] = day
+            .solution
+            .bench(day.input_text, config)
+            .map_err(|e| format!("{} day {}: {}", day.year, day.day, e))?;
+        for (phase, stats) in days::PHASES.iter().zip(all_stats) {
+            let (change, is_regression) = match history.baseline(&commit, day.year, day.day, phase)
+            {
+                Some(baseline) => {
+                    let change = history::median_change(&baseline.stats, &stats);
+                    (format!("{:+.1}%", change), change > threshold)
+                }
+                None => ("-".to_string(), false),
+            };
+            println!(
+                "{:>4} {:>3} {:<6} {:>10} {:>10} {:>10} {:>9}{}",
+                day.year,
+                day.day,
+                phase,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.p95),
+                change,
+                if is_regression { "  REGRESSION" } else { "" }
+            );
+            if is_regression {
+                regressions += 1;
+            }
+            history.record(Record {
+                commit: commit.clone(),
+                year: day.year,
+                day: day.day,
+                phase: phase.to_string(),
+                stats,
+            });
+        }
+    }
+
+    if let Some(path) = &history_path {
+        history.save(path).map_err(|e| e.to_string())?;
+        println!();
+        println!("Results for {} saved to {}", commit, path.display());
+    }
+    if regressions > 0 {
+        println!(
+            "{} phase(s) got more than {}% slower than the previous commit",
+            regressions, threshold
+        );
+    }
+    Ok(regressions)
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
@@ -0,0 +1,98 @@
+use aoc_common::bench::{self, BenchConfig, Stats};
+use aoc_common::rng::Rng;
+use aoc_common::{Error, Result, Solution};
+use std::io::{self, Write};
+
+/// Names of the phases measured by [`Runnable::bench`], in the order they are returned
+pub const PHASES: [&str; 3] = ["parse", "part 1", "part 2"];
+
+/// Object-safe view of a [`Solution`], so that days with different answer types can live in the
+/// same table
+pub trait Runnable: Sync {
+    fn run(&self, part: u32, input_text: &str) -> Result<String>;
+
+    /// Measures parsing and solving each part separately. Solving is measured over an input that
+    /// has already been parsed.
+    fn bench(&self, input_text: &str, config: BenchConfig) -> Result<[Stats; 3]>;
+}
+
+impl<S: Solution + Sync> Runnable for S {
+    fn run(&self, part: u32, input_text: &str) -> Result<String> {
+        let parsed: S::Parsed = self.parse(input_text)?;
+        match part {
+            1 => self.part1(&parsed).map(|answer| answer.to_string()),
+            2 => self.part2(&parsed).map(|answer| answer.to_string()),
+            _ => Err(Error::Invalid(format!("There is no part {}", part))),
+        }
+    }
+
+    fn bench(&self, input_text: &str, config: BenchConfig) -> Result<[Stats; 3]> {
+        // Run everything once first, so that errors are reported instead of being measured
+        let parsed: S::Parsed = self.parse(input_text)?;
+        self.part1(&parsed)?;
+        self.part2(&parsed)?;
+
+        Ok([
+            bench::measure(config, || self.parse(input_text)),
+            bench::measure(config, || self.part1(&parsed)),
+            bench::measure(config, || self.part2(&parsed)),
+        ])
+    }
+}
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub input_text: &'static str,
+    pub answers: &'static str,
+    pub solution: &'static dyn Runnable,
+    /// Writes a random input with the given number of lines
+    pub generate: fn(&mut Rng, usize, &mut dyn Write) -> io::Result<()>,
+}
+
+pub const ALL: &[Day] = &[
+    Day {
+        year: 2023,
+        day: 1,
+        input_text: y2023_d01::INPUT,
+        answers: y2023_d01::ANSWERS,
+        solution: &y2023_d01::Day01,
+        generate: y2023_d01::generator::generate,
+    },
+    Day {
+        year: 2023,
+        day: 2,
+        input_text: y2023_d02::INPUT,
+        answers: y2023_d02::ANSWERS,
+        solution: &y2023_d02::Day02,
+        generate: y2023_d02::generator::generate,
+    },
+    Day {
+        year: 2023,
+        day: 3,
+        input_text: y2023_d03::INPUT,
+        answers: y2023_d03::ANSWERS,
+        solution: &y2023_d03::Day03,
+        generate: y2023_d03::generator::generate,
+    },
+    Day {
+        year: 2024,
+        day: 1,
+        input_text: y2024_d01::INPUT,
+        answers: y2024_d01::ANSWERS,
+        solution: &y2024_d01::Day01,
+        generate: y2024_d01::generator::generate,
+    },
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    ALL.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// Days matching the optional `[year, day]` filters given on the command line
+pub fn filter(filters: &[u32]) -> impl Iterator<Item = &'static Day> + '_ {
+    ALL.iter().filter(|d| {
+        filters.first().is_none_or(|&year| d.year == year)
+            && filters.get(1).is_none_or(|&day| d.day == day)
+    })
+}
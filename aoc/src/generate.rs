@@ -0,0 +1,36 @@
+use crate::{days, flag_value};
+use aoc_common::rng::Rng;
+use std::io::{self, BufWriter, Write};
+
+const DEFAULT_LINES: usize = 1000;
+const DEFAULT_SEED: u64 = 1;
+
+/// Writes a random input for a day to stdout, without keeping it in memory. The same seed and
+/// number of lines always give the same input.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut lines: usize = DEFAULT_LINES;
+    let mut seed: u64 = DEFAULT_SEED;
+    let mut numbers: Vec<u32> = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lines" => lines = flag_value(arg, args.next())?,
+            "--seed" => seed = flag_value(arg, args.next())?,
+            _ if numbers.len() < 2 => numbers.push(
+                arg.parse()
+                    .map_err(|_| format!("Expected a year or a day, got {}", arg))?,
+            ),
+            _ => return Err(format!("Unexpected argument {}", arg)),
+        }
+    }
+    let [year, day] = numbers[..] else {
+        return Err("Expected a year and a day".to_string());
+    };
+    let day_solution: &days::Day = days::find(year, day)
+        .ok_or_else(|| format!("No solution for year {} day {}", year, day))?;
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    (day_solution.generate)(&mut Rng::new(seed), lines, &mut out)
+        .and_then(|_| out.flush())
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,210 @@
+use aoc_common::bench::Stats;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+const HEADER: &str = "commit\tyear\tday\tphase\tmin_ns\tmedian_ns\tp95_ns";
+
+/// Benchmark results of one phase of one day, measured at some commit
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub commit: String,
+    pub year: u32,
+    pub day: u32,
+    pub phase: String,
+    pub stats: Stats,
+}
+
+/// Benchmark results of previous runs, stored as a tab-separated file with one [`Record`] per
+/// line. Only the latest run of each commit is kept.
+#[derive(Debug, Default)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl Record {
+    fn is_same_phase(&self, year: u32, day: u32, phase: &str) -> bool {
+        self.year == year && self.day == day && self.phase == phase
+    }
+}
+
+impl History {
+    /// Loads the history from `path`. A missing file is an empty history.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_tsv())
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut records: Vec<Record> = Vec::new();
+        for (line_index, line) in text.lines().enumerate().skip(1) {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let error = || format!("line {}: malformed record", line_index + 1);
+            if fields.len() != 7 {
+                return Err(error());
+            }
+            let nanos = |field: &str| field.parse().map(Duration::from_nanos).map_err(|_| error());
+            records.push(Record {
+                commit: fields[0].to_string(),
+                year: fields[1].parse().map_err(|_| error())?,
+                day: fields[2].parse().map_err(|_| error())?,
+                phase: fields[3].to_string(),
+                stats: Stats {
+                    min: nanos(fields[4])?,
+                    median: nanos(fields[5])?,
+                    p95: nanos(fields[6])?,
+                },
+            });
+        }
+        Ok(History { records })
+    }
+
+    fn to_tsv(&self) -> String {
+        let mut text: String = HEADER.to_string() + "\n";
+        for r in &self.records {
+            text += &format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                r.commit,
+                r.year,
+                r.day,
+                r.phase,
+                r.stats.min.as_nanos(),
+                r.stats.median.as_nanos(),
+                r.stats.p95.as_nanos()
+            );
+        }
+        text
+    }
+
+    /// The most recent result of the phase measured at any commit other than `commit`
+    pub fn baseline(&self, commit: &str, year: u32, day: u32, phase: &str) -> Option<&Record> {
+        self.records
+            .iter()
+            .rev()
+            .find(|r| r.commit != commit && r.is_same_phase(year, day, phase))
+    }
+
+    /// Adds a result, replacing any earlier result of the same phase at the same commit
+    pub fn record(&mut self, record: Record) {
+        self.records.retain(|r| {
+            r.commit != record.commit || !r.is_same_phase(record.year, record.day, &record.phase)
+        });
+        self.records.push(record);
+    }
+}
+
+/// Relative change of the median between a baseline and a new measurement, in percent
+pub fn median_change(baseline: &Stats, current: &Stats) -> f64 {
+    let baseline_nanos = baseline.median.as_nanos() as f64;
+    let current_nanos = current.median.as_nanos() as f64;
+    if baseline_nanos == 0.0 {
+        0.0
+    } else {
+        (current_nanos - baseline_nanos) / baseline_nanos * 100.0
+    }
+}
+
+/// Short hash of the checked out commit, with a `-dirty` suffix if there are uncommitted changes.
+/// Falls back to `unknown` outside of a git repository.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git").args(args).output().ok()?;
+        if output.status.success() {
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        } else {
+            None
+        }
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain"]) {
+            Some(status) if !status.is_empty() => hash + "-dirty",
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Default location of the history file, relative to the current directory
+pub fn default_path() -> PathBuf {
+    PathBuf::from("bench_history.tsv")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, phase: &str, median_ms: u64) -> Record {
+        let median = Duration::from_millis(median_ms);
+        Record {
+            commit: commit.to_string(),
+            year: 2023,
+            day: 1,
+            phase: phase.to_string(),
+            stats: Stats {
+                min: median,
+                median,
+                p95: median,
+            },
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut history = History::default();
+        history.record(record("abc", "parse", 1));
+        history.record(record("abc", "part 1", 2));
+        let parsed = History::parse(&history.to_tsv()).unwrap();
+        assert_eq!(history.records, parsed.records);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(
+            Err("line 2: malformed record".to_string()),
+            History::parse(&(HEADER.to_string() + "\nabc\t2023\t1\tparse\t1\t2"))
+                .map(|h| h.records)
+        );
+    }
+
+    #[test]
+    fn test_baseline() {
+        let mut history = History::default();
+        history.record(record("aaa", "parse", 1));
+        history.record(record("bbb", "parse", 2));
+        history.record(record("ccc", "parse", 3));
+        // Running again at the same commit replaces the previous result
+        history.record(record("ccc", "parse", 4));
+        assert_eq!(3, history.records.len());
+        assert_eq!(
+            Duration::from_millis(2),
+            history
+                .baseline("ccc", 2023, 1, "parse")
+                .unwrap()
+                .stats
+                .median
+        );
+        assert!(history.baseline("ccc", 2023, 1, "part 1").is_none());
+    }
+
+    #[test]
+    fn test_median_change() {
+        let baseline = record("aaa", "parse", 100).stats;
+        let current = record("bbb", "parse", 125).stats;
+        assert_eq!(25.0, median_change(&baseline, &current));
+        assert_eq!(-20.0, median_change(&current, &baseline));
+    }
+}
@@ -0,0 +1,128 @@
+use answers::{Answers, Status};
+use aoc_common::{input, OrExit};
+use std::borrow::Cow;
+use std::env;
+use std::process;
+use std::time::{Duration, Instant};
+mod answers;
+mod bench;
+mod days;
+mod generate;
+mod history;
+
+const USAGE: &str = "Usage:
+    aoc <year> <day> <part> [input path, or - for stdin]
+    aoc check [year [day]]
+    aoc bench [year [day]] [--samples N] [--warmup N] [--threshold PERCENT]
+              [--history PATH | --no-history]
+    aoc generate <year> <day> [--lines N] [--seed N]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("check") => check(&args[1..]),
+        Some("bench") => match bench::run(&args[1..]) {
+            Ok(0) => {}
+            Ok(_) => process::exit(1),
+            Err(e) => {
+                eprintln!("{}", e);
+                usage();
+            }
+        },
+        Some("generate") => {
+            if let Err(e) = generate::run(&args[1..]) {
+                eprintln!("{}", e);
+                usage();
+            }
+        }
+        _ => run(&args),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+/// Parses the number following `flag` on the command line of a subcommand
+fn flag_value<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+    value
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| format!("{} expects a number", flag))
+}
+
+/// Runs a single part of a single day, and checks the answer when the bundled input is used
+fn run(args: &[String]) {
+    let numbers: Vec<u32> = args.iter().take(3).filter_map(|a| a.parse().ok()).collect();
+    if !(3..=4).contains(&args.len()) || numbers.len() != 3 {
+        usage();
+    }
+    let (year, day, part): (u32, u32, u32) = (numbers[0], numbers[1], numbers[2]);
+
+    let day_solution: &days::Day = match days::find(year, day) {
+        Some(day_solution) if part == 1 || part == 2 => day_solution,
+        _ => {
+            eprintln!("No solution for year {} day {} part {}", year, day, part);
+            process::exit(1);
+        }
+    };
+
+    let input_path: Option<&str> = args.get(3).map(String::as_str);
+    let input_text: Cow<str> = input::load(input_path, day_solution.input_text).or_exit();
+
+    let start: Instant = Instant::now();
+    let result: String = day_solution.solution.run(part, &input_text).or_exit();
+    let duration: Duration = start.elapsed();
+
+    println!("Result: {}", result);
+    println!("Execution time: {:.2?}", duration);
+
+    // Recorded answers only apply to the bundled input
+    if input_path.is_none() {
+        let answers = Answers::parse(day_solution.answers).expect("Should have parsed answers");
+        println!("Check: {}", answers.check(part, &result));
+    }
+}
+
+/// Runs every part of every day (optionally only for one year or day) over the bundled input and
+/// compares the results with the recorded answers. Exits with an error if any of them is wrong.
+fn check(args: &[String]) {
+    let filters: Vec<u32> = args.iter().filter_map(|a| a.parse().ok()).collect();
+    if args.len() > 2 || filters.len() != args.len() {
+        usage();
+    }
+
+    let (mut passed, mut failed, mut unknown): (u32, u32, u32) = (0, 0, 0);
+    for day in days::filter(&filters) {
+        let answers = Answers::parse(day.answers).expect("Should have parsed answers");
+        for part in [1, 2] {
+            let result: String = match day.solution.run(part, day.input_text) {
+                Ok(result) => result,
+                Err(e) => {
+                    failed += 1;
+                    println!(
+                        "{} day {:>2} part {}: ERROR ({})",
+                        day.year, day.day, part, e
+                    );
+                    continue;
+                }
+            };
+            let status: Status = answers.check(part, &result);
+            match status {
+                Status::Pass => passed += 1,
+                Status::Fail { .. } => failed += 1,
+                Status::Unknown => unknown += 1,
+            }
+            println!(
+                "{} day {:>2} part {}: {} (got {})",
+                day.year, day.day, part, status, result
+            );
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    if failed > 0 {
+        process::exit(1);
+    }
+}
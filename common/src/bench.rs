@@ -0,0 +1,93 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How many times a function is run before and while measuring it
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            samples: 25,
+        }
+    }
+}
+
+/// Summary of the durations of all the samples of a benchmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of a non-empty list of samples, using the nearest-rank method for
+    /// the percentiles
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Should have at least one sample");
+        samples.sort();
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Stats {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+/// Runs `f` `config.warmup` times without measuring it, and then `config.samples` times
+/// measuring each run separately. The result of `f` is passed through [`black_box`] so that the
+/// compiler can't optimise the work away.
+pub fn measure<R>(config: BenchConfig, mut f: impl FnMut() -> R) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples: Vec<Duration> = (0..config.samples.max(1))
+        .map(|_| {
+            let start: Instant = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(10), stats.median);
+        assert_eq!(Duration::from_millis(19), stats.p95);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(vec![Duration::from_millis(3)]);
+        assert_eq!(Duration::from_millis(3), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(3), stats.p95);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls: usize = 0;
+        measure(
+            BenchConfig {
+                warmup: 2,
+                samples: 5,
+            },
+            || calls += 1,
+        );
+        assert_eq!(7, calls);
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+use std::io;
+use std::process;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// A malformed line of the puzzle input
+    Parse {
+        /// 1-based line number
+        line: usize,
+        /// 1-based byte offset in the line where the problem starts
+        column: usize,
+        /// The offending text
+        text: String,
+        /// What was expected instead
+        message: String,
+    },
+    /// Anything else that makes a solution impossible, e.g. asking for a part that doesn't exist
+    Invalid(String),
+    /// An answer, or a number needed to compute it, too large for its type
+    Overflow(&'static str),
+    Io(io::Error),
+}
+
+impl Error {
+    /// A parse error, given the 0-based index of the line as returned by `lines().enumerate()`
+    pub fn parse(
+        line_index: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Error::Parse {
+            line: line_index + 1,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                text,
+                message,
+            } => write!(
+                f,
+                "line {}: {} (column {}: {:?})",
+                line, message, column, text
+            ),
+            Error::Invalid(message) => write!(f, "{}", message),
+            Error::Overflow(type_name) => write!(
+                f,
+                "arithmetic overflow: the result doesn't fit in a {} (see the `bigint` feature)",
+                type_name
+            ),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// For binaries: report errors to the user instead of panicking with a backtrace
+pub trait OrExit<T> {
+    /// Returns the value, or prints the error and exits with status 1
+    fn or_exit(self) -> T;
+}
+
+impl<T, E: fmt::Display> OrExit<T> for std::result::Result<T, E> {
+    fn or_exit(self) -> T {
+        self.unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "line 17: expected two integers (column 1: \"12\")",
+            Error::parse(16, 1, "12", "expected two integers").to_string()
+        );
+    }
+}
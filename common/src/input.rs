@@ -0,0 +1,98 @@
+use crate::error::Result;
+use std::borrow::Cow;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+
+/// Loads the puzzle input at runtime, so that the same build can be run against anyone's input.
+///
+/// `path` is usually the first command line argument:
+/// - `None` falls back to the `bundled` input (the one compiled in with `include_str!`)
+/// - `Some("-")` reads the whole standard input
+/// - `Some(path)` reads the file at that path
+pub fn load(path: Option<&str>, bundled: &'static str) -> io::Result<Cow<'static, str>> {
+    match path {
+        None => Ok(Cow::Borrowed(bundled)),
+        Some("-") => {
+            let mut input_text = String::new();
+            io::stdin().read_to_string(&mut input_text)?;
+            Ok(Cow::Owned(input_text))
+        }
+        Some(path) => fs::read_to_string(path).map(Cow::Owned),
+    }
+}
+
+/// Opens the puzzle input for reading it line by line, without loading it all in memory. `path`
+/// is handled like in [`load`].
+pub fn open(path: Option<&str>, bundled: &'static str) -> io::Result<Box<dyn BufRead>> {
+    Ok(match path {
+        None => Box::new(bundled.as_bytes()),
+        Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+    })
+}
+
+/// Calls `f` with the index and the content of every line of `reader`, like [`str::lines`] does
+/// for a string. The same buffer is reused for all the lines, so memory use only depends on the
+/// length of the longest line. Stops at the first error, of `f` or of the reader.
+pub fn for_each_line(
+    mut reader: impl BufRead,
+    mut f: impl FnMut(usize, &str) -> Result<()>,
+) -> Result<()> {
+    let mut buffer = String::new();
+    let mut line_index: usize = 0;
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(());
+        }
+        let line: &str = match buffer.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => &buffer,
+        };
+        f(line_index, line)?;
+        line_index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_bundled() {
+        assert_eq!("bundled", load(None, "bundled").unwrap());
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        assert!(load(Some("does/not/exist.txt"), "bundled").is_err());
+    }
+
+    #[test]
+    fn test_for_each_line() {
+        let text = "a\r\nbb\n\nccc";
+        let mut lines: Vec<(usize, String)> = Vec::new();
+        for_each_line(text.as_bytes(), |i, line| {
+            lines.push((i, line.to_string()));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            text.lines()
+                .enumerate()
+                .map(|(i, l)| (i, l.to_string()))
+                .collect::<Vec<_>>(),
+            lines
+        );
+
+        let result = for_each_line(text.as_bytes(), |i, line| match i {
+            1 => Err(crate::Error::parse(i, 1, line, "stop")),
+            _ => Ok(()),
+        });
+        assert_eq!(
+            "line 2: stop (column 1: \"bb\")",
+            result.unwrap_err().to_string()
+        );
+        assert!(for_each_line(&b"\xff\n"[..], |_, _| Ok(())).is_err());
+    }
+}
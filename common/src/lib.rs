@@ -0,0 +1,16 @@
+pub mod bench;
+mod error;
+pub mod input;
+pub mod number;
+pub mod parse;
+mod regex_cache;
+pub mod rng;
+mod solution;
+
+pub use error::{Error, OrExit, Result};
+pub use solution::Solution;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use regex::Regex;
+}
@@ -0,0 +1,96 @@
+//! Checked arithmetic for answers that grow with the size of the input
+
+use crate::{Error, Result};
+
+/// The type of answers that grow with the size of the input: `u64`, or an arbitrary-precision
+/// `BigUint` with the `bigint` feature, for scaled-up generated inputs
+#[cfg(not(feature = "bigint"))]
+pub type Answer = u64;
+#[cfg(feature = "bigint")]
+pub type Answer = num_bigint::BigUint;
+
+/// Arithmetic that reports overflow as an [`Error::Overflow`], instead of panicking in debug builds
+/// and silently wrapping around in release builds
+pub trait Checked: Sized {
+    fn try_add(self, rhs: Self) -> Result<Self>;
+    fn try_mul(self, rhs: Self) -> Result<Self>;
+}
+
+macro_rules! impl_checked {
+    ($($t:ty),*) => {
+        $(
+            impl Checked for $t {
+                fn try_add(self, rhs: Self) -> Result<Self> {
+                    self.checked_add(rhs).ok_or(Error::Overflow(stringify!($t)))
+                }
+
+                fn try_mul(self, rhs: Self) -> Result<Self> {
+                    self.checked_mul(rhs).ok_or(Error::Overflow(stringify!($t)))
+                }
+            }
+        )*
+    };
+}
+
+impl_checked!(u32, u64, u128);
+
+#[cfg(feature = "bigint")]
+impl Checked for num_bigint::BigUint {
+    fn try_add(self, rhs: Self) -> Result<Self> {
+        Ok(self + rhs)
+    }
+
+    fn try_mul(self, rhs: Self) -> Result<Self> {
+        Ok(self * rhs)
+    }
+}
+
+/// Sum of all `values`, 0 if there are none
+pub fn sum<T: Checked + From<u8>>(values: impl IntoIterator<Item = T>) -> Result<T> {
+    values.into_iter().try_fold(T::from(0), T::try_add)
+}
+
+/// Product of all `values`, 1 if there are none
+pub fn product<T: Checked + From<u8>>(values: impl IntoIterator<Item = T>) -> Result<T> {
+    values.into_iter().try_fold(T::from(1), T::try_mul)
+}
+
+/// Number of bits needed to write `value`, 0 for 0
+#[cfg(not(feature = "bigint"))]
+pub fn bits(value: &Answer) -> u64 {
+    u64::from(Answer::BITS - value.leading_zeros())
+}
+
+/// Number of bits needed to write `value`, 0 for 0
+#[cfg(feature = "bigint")]
+pub fn bits(value: &Answer) -> u64 {
+    value.bits()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(10u32, sum([1u32, 2, 3, 4]).unwrap());
+        assert_eq!(24u64, product([1u64, 2, 3, 4]).unwrap());
+        assert_eq!(0u32, sum(Vec::<u32>::new()).unwrap());
+        assert_eq!(1u32, product(Vec::<u32>::new()).unwrap());
+        assert_eq!(
+            "arithmetic overflow: the result doesn't fit in a u32 (see the `bigint` feature)",
+            sum([u32::MAX, 1]).unwrap_err().to_string()
+        );
+        assert!(product([u64::MAX, 2]).is_err());
+    }
+
+    #[test]
+    fn test_answer() {
+        // Past u64::MAX, answers only fit with the bigint feature
+        let big = product([Answer::from(u64::MAX), Answer::from(2u8)]);
+        assert_eq!(cfg!(feature = "bigint"), big.is_ok());
+        assert_eq!(0, bits(&Answer::from(0u8)));
+        assert_eq!(3, bits(&Answer::from(5u8)));
+        assert_eq!(64, bits(&Answer::from(u64::MAX)));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::error::{Error, Result};
+use std::str::FromStr;
+
+/// The 1-based column at which `part`, a subslice of `line`, starts
+pub fn column(line: &str, part: &str) -> usize {
+    part.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+/// Splits a line on whitespace, returning each field with its 1-based column
+pub fn fields(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace()
+        .map(move |field| (column(line, field), field))
+}
+
+/// Parses a single field, reporting `expected` with the field's position if it is malformed
+pub fn field<T: FromStr>(
+    line_index: usize,
+    (column, field): (usize, &str),
+    expected: &str,
+) -> Result<T> {
+    field
+        .parse()
+        .map_err(|_| Error::parse(line_index, column, field, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields() {
+        let all: Vec<(usize, &str)> = fields("  12   345 x").collect();
+        assert_eq!(vec![(3, "12"), (8, "345"), (12, "x")], all);
+        assert_eq!(0, fields("   ").count());
+        let line = "Game 7: 1 red";
+        assert_eq!(9, column(line, &line[8..]));
+    }
+
+    #[test]
+    fn test_field() {
+        assert_eq!(12u32, field(0, (3, "12"), "expected an integer").unwrap());
+        assert_eq!(
+            "line 5: expected an integer (column 12: \"x\")",
+            field::<u32>(4, (12, "x"), "expected an integer")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,33 @@
+/// Returns a `&'static Regex` for a literal pattern, compiling it only the first time the
+/// expression is evaluated. Use it instead of `Regex::new` in functions called once per line or
+/// per character.
+///
+/// ```
+/// let digit = aoc_common::regex!(r"\d");
+/// assert!(digit.is_match("a1"));
+/// ```
+#[macro_export]
+macro_rules! regex {
+    ($pattern:expr $(,)?) => {{
+        static REGEX: ::std::sync::LazyLock<$crate::__private::Regex> =
+            ::std::sync::LazyLock::new(|| $crate::__private::Regex::new($pattern).unwrap());
+        &*REGEX
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+    use std::ptr;
+
+    fn digit() -> &'static Regex {
+        regex!(r"\d")
+    }
+
+    #[test]
+    fn test_compiled_once() {
+        assert!(ptr::eq(digit(), digit()));
+        assert!(digit().is_match("a1"));
+        assert!(!regex!("^b").is_match("a1"));
+    }
+}
@@ -0,0 +1,16 @@
+use crate::Result;
+use std::fmt::Display;
+
+/// A single day of Advent of Code.
+///
+/// The input is parsed once and the result is shared by both parts, so that a runner, a test
+/// harness or a benchmark can treat every day the same way regardless of its answer types.
+pub trait Solution {
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input_text: &str) -> Result<Self::Parsed>;
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Self::Answer1>;
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Self::Answer2>;
+}
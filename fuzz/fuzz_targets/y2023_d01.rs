@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2023_d01::{p1, p2};
+
+fuzz_target!(|data: &[u8]| {
+    // The streaming parts read raw bytes, and must reject invalid UTF-8 with an error
+    let streamed_p1 = p1::run_reader(data).ok();
+    let Ok(input_text) = std::str::from_utf8(data) else {
+        assert_eq!(None, streamed_p1);
+        return;
+    };
+    assert_eq!(p1::run(input_text).ok(), streamed_p1);
+    assert_eq!(
+        p2::run(input_text).ok(),
+        p2::run_reader_with(data, &Default::default()).ok()
+    );
+
+    let lines = y2023_d01::parse(input_text).unwrap();
+    let _ = p1::explain(&lines);
+    let _ = p2::explain_with(&lines, &Default::default());
+    let _ = p2::diagnose(input_text);
+});
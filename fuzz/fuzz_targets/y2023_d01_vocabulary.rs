@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2023_d01::p2;
+use y2023_d01::vocabulary::{Decoder, Vocabulary};
+
+fuzz_target!(|vocabulary_text: &str| {
+    if let Ok(vocabulary) = Vocabulary::parse(vocabulary_text) {
+        // Decode the vocabulary's own text, which is sure to contain its words
+        let decoder = Decoder::new(&vocabulary);
+        let lines = y2023_d01::parse(vocabulary_text).unwrap();
+        let _ = p2::solve_with(&lines, &decoder);
+        let _ = p2::explain_with(&lines, &decoder);
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2023_d02::bag::Bag;
+use y2023_d02::stats::Stats;
+
+fuzz_target!(|data: &[u8]| {
+    // The streaming part reads raw bytes, and must reject invalid UTF-8 with an error
+    let streamed = y2023_d02::p1::run_reader(data, &Bag::default()).ok();
+    let Ok(input_text) = std::str::from_utf8(data) else {
+        assert_eq!(None, streamed);
+        return;
+    };
+    // Solving line by line as the input is read must agree with parsing the whole text first
+    let Ok(games) = y2023_d02::parse(input_text) else {
+        assert_eq!(None, streamed);
+        return;
+    };
+    assert_eq!(y2023_d02::p1::solve(&games).ok(), streamed);
+    // Large counts may overflow the answer, which must be an error
+    let _ = y2023_d02::p2::solve(&games);
+    let _ = Stats::compute(&games, &Bag::default());
+});
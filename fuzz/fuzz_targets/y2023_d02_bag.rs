@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2023_d02::bag::Bag;
+
+fuzz_target!(|bag_text: &str| {
+    if let Ok(bag) = Bag::parse(bag_text) {
+        let _ = bag.to_string();
+        let _ = y2023_d02::parse_with(bag_text, &bag);
+    }
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input_text: &str| {
+    let all_chars = y2023_d03::parse(input_text).unwrap();
+    assert_eq!(input_text.lines().count(), all_chars.height());
+    for (row, line) in all_chars.rows().zip(input_text.lines()) {
+        // Shorter lines are padded with empty cells
+        let (cells, padding) = row.split_at(line.chars().count());
+        assert_eq!(line, cells.iter().collect::<String>());
+        assert!(padding.iter().all(|&ch| ch == '.'));
+    }
+    // Long runs of digits may overflow the answer, which must be an error
+    let _ = y2023_d03::p1::solve(&all_chars);
+    let _ = y2023_d03::p2::solve(&all_chars);
+});
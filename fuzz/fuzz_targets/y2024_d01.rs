@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use y2024_d01::metrics::Metric;
+
+fuzz_target!(|data: &[u8]| {
+    let streamed = y2024_d01::parse_reader(data).ok();
+    let Ok(input_text) = std::str::from_utf8(data) else {
+        assert_eq!(None, streamed);
+        return;
+    };
+    assert_eq!(y2024_d01::parse(input_text).ok(), streamed);
+    if let Some(lists) = streamed {
+        let _ = y2024_d01::p1::solve(&lists);
+        let _ = y2024_d01::p2::solve(&lists);
+    }
+    if let Ok(pairs) = y2024_d01::parse_pairs(input_text) {
+        let lists = y2024_d01::sorted(pairs.clone());
+        for metric in Metric::ALL {
+            let _ = metric.compute(&pairs, &lists);
+        }
+    }
+});
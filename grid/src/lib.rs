@@ -0,0 +1,407 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+mod position;
+
+pub use position::{Direction, Direction8, Offset, Position};
+
+/// A rectangular 2D grid, stored row by row.
+///
+/// Ragged input can be turned into a rectangular grid with [`Grid::parse_padded`]. Positions are
+/// `(row, col)` pairs, with `(0, 0)` being the top left corner. All accessors are bounds-checked,
+/// so callers never have to special-case the corners or the edges of the grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A row does not have the same length as the first one
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} columns, expected {}",
+                row + 1,
+                found,
+                expected
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl Grid<char> {
+    /// Builds a grid with one row per line of text and one cell per character
+    pub fn parse(text: &str) -> Result<Self, GridError> {
+        Self::parse_with(text, |ch| ch)
+    }
+
+    /// Like [`Grid::parse`], but lines of different length are accepted: the grid is as wide as
+    /// the longest line, and shorter lines are padded at the end with `fill`
+    pub fn parse_padded(text: &str, fill: char) -> Self {
+        Self::from_rows_padded(text.lines().map(|line| line.chars()), fill)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a grid from rows of any length, padding the shorter ones at the end with `fill`
+    pub fn from_rows_padded<R>(rows: impl IntoIterator<Item = R>, fill: T) -> Self
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let rows: Vec<Vec<T>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect();
+        let width: usize = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut cells: Vec<T> = Vec::with_capacity(width * rows.len());
+        for row in &rows {
+            cells.extend(row.iter().cloned());
+            cells.extend(std::iter::repeat_n(fill.clone(), width - row.len()));
+        }
+        Grid {
+            width,
+            height: rows.len(),
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid with one row per line of text, converting every character with `f`
+    pub fn parse_with(text: &str, f: impl Fn(char) -> T) -> Result<Self, GridError> {
+        Self::from_rows(text.lines().map(|line| line.chars().map(&f)))
+    }
+
+    /// Builds a grid from its rows, which must all have the same length
+    pub fn from_rows<R>(rows: impl IntoIterator<Item = R>) -> Result<Self, GridError>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut width: usize = 0;
+        let mut height: usize = 0;
+        let mut cells: Vec<T> = Vec::new();
+        for (row_index, row) in rows.into_iter().enumerate() {
+            let row_start = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - row_start;
+            if row_index == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(GridError::Ragged {
+                    row: row_index,
+                    expected: width,
+                    found: row_width,
+                });
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.row * self.width + position.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.row * self.width + position.col])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `position` by `offset`, or returns `None` if the result is outside of the grid
+    pub fn checked_add(&self, position: Position, offset: Offset) -> Option<Position> {
+        position.checked_add_within(offset, self.height, self.width)
+    }
+
+    /// All positions of the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Position::new(i / width, i % width))
+    }
+
+    /// All cells of the grid together with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics with a chunk size of 0, and a grid without columns has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).filter_map(move |row| self.get(Position::new(row, col)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Cells starting at `start` (included) and moving by `offset` until leaving the grid
+    pub fn ray(&self, start: Position, offset: Offset) -> impl Iterator<Item = &T> {
+        let mut next: Option<Position> = Some(start).filter(|&p| self.contains(p));
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = self.checked_add(current, offset);
+            self.get(current)
+        })
+    }
+
+    /// Cells going down and to the right from `start`, starting with `start` itself
+    pub fn diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction8::DownRight.offset())
+    }
+
+    /// Cells going down and to the left from `start`, starting with `start` itself
+    pub fn anti_diagonal(&self, start: Position) -> impl Iterator<Item = &T> {
+        self.ray(start, Direction8::DownLeft.offset())
+    }
+
+    /// Every down-right diagonal of the grid, from the bottom left corner to the top right one
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|row| Position::new(row, 0))
+            .chain((1..self.width).map(|col| Position::new(0, col)));
+        starts.map(move |start| self.diagonal(start))
+    }
+
+    /// Every down-left diagonal of the grid, from the top left corner to the bottom right one
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_col = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|col| Position::new(0, col))
+            .chain((1..self.height).map(move |row| Position::new(row, last_col)));
+        starts.map(move |start| self.anti_diagonal(start))
+    }
+
+    /// Positions of the (up to 4) orthogonal neighbours of `position` that are inside the grid,
+    /// clockwise starting from the one above
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_add(position, direction.offset()))
+    }
+
+    /// Positions of the (up to 8) surrounding neighbours of `position` that are inside the grid,
+    /// clockwise starting from the one above
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_add(position, direction.offset()))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "Position {} is outside of a {}x{} grid",
+                position, self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!(
+                "Position {} is outside of a {}x{} grid",
+                position, height, width
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_string<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Some(&'e'), grid.get(Position::new(1, 1)));
+        assert_eq!(None, grid.get(Position::new(2, 0)));
+        assert_eq!(None, grid.get(Position::new(0, 3)));
+        assert_eq!('f', grid[Position::new(1, 2)]);
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let grid = Grid::parse("").unwrap();
+        assert!(grid.is_empty());
+        assert_eq!(0, grid.rows().count());
+        assert_eq!(0, grid.positions().count());
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert_eq!(
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            }),
+            Grid::parse("abc\nde")
+        );
+    }
+
+    #[test]
+    fn test_parse_padded() {
+        let grid = Grid::parse_padded("a\nbcd\n\nef", '.');
+        assert_eq!(3, grid.width());
+        assert_eq!(4, grid.height());
+        let rows: Vec<String> = grid.rows().map(|r| to_string(r.iter())).collect();
+        assert_eq!(vec!["a..", "bcd", "...", "ef."], rows);
+        assert_eq!(
+            Grid::parse("ab\ncd").unwrap(),
+            Grid::parse_padded("ab\ncd", '.')
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        let rows: Vec<String> = grid.rows().map(|r| to_string(r.iter())).collect();
+        assert_eq!(vec!["abc", "def"], rows);
+        let columns: Vec<String> = grid.columns().map(to_string).collect();
+        assert_eq!(vec!["ad", "be", "cf"], columns);
+        assert_eq!(None, grid.row(2));
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let grid = Grid::parse("abc\ndef\nghi").unwrap();
+        let diagonals: Vec<String> = grid.diagonals().map(to_string).collect();
+        assert_eq!(vec!["g", "dh", "aei", "bf", "c"], diagonals);
+        let anti_diagonals: Vec<String> = grid.anti_diagonals().map(to_string).collect();
+        assert_eq!(vec!["a", "bd", "ceg", "fh", "i"], anti_diagonals);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::parse("abc\ndef\nghi").unwrap();
+        assert_eq!(
+            "ihg",
+            to_string(grid.ray(Position::new(2, 2), Direction::Left.offset()))
+        );
+        assert_eq!(
+            "",
+            to_string(grid.ray(Position::new(3, 0), Direction::Up.offset()))
+        );
+        assert_eq!(
+            "ceg",
+            to_string(grid.ray(Position::new(0, 2), Offset::new(1, -1)))
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse("abc\ndef\nghi").unwrap();
+        let corner: Vec<Position> = grid.neighbours8(Position::new(0, 0)).collect();
+        assert_eq!(
+            vec![
+                Position::new(0, 1),
+                Position::new(1, 1),
+                Position::new(1, 0)
+            ],
+            corner
+        );
+        assert_eq!(8, grid.neighbours8(Position::new(1, 1)).count());
+        assert_eq!(5, grid.neighbours8(Position::new(2, 1)).count());
+        let edge: Vec<Position> = grid.neighbours4(Position::new(1, 2)).collect();
+        assert_eq!(
+            vec![
+                Position::new(0, 2),
+                Position::new(2, 2),
+                Position::new(1, 1)
+            ],
+            edge
+        );
+    }
+
+    #[test]
+    fn test_neighbours_single_cell() {
+        let grid = Grid::parse("a").unwrap();
+        assert_eq!(0, grid.neighbours8(Position::new(0, 0)).count());
+        assert_eq!(0, grid.neighbours4(Position::new(0, 0)).count());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut grid = Grid::parse_with("12\n34", |ch| ch.to_digit(10).unwrap()).unwrap();
+        grid[Position::new(1, 0)] = 9;
+        let cells: Vec<(Position, &u32)> = grid.iter().collect();
+        assert_eq!(
+            vec![
+                (Position::new(0, 0), &1),
+                (Position::new(0, 1), &2),
+                (Position::new(1, 0), &9),
+                (Position::new(1, 1), &4)
+            ],
+            cells
+        );
+    }
+}
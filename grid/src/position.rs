@@ -0,0 +1,333 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// A cell of a grid. Rows grow downwards and columns grow to the right, so `(0, 0)` is the top
+/// left corner. Positions can't be negative: moving them is done with an [`Offset`] and the
+/// `checked_add*` methods.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A signed displacement between two [`Position`]s
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Offset {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// The 4 orthogonal directions, in clockwise order
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// The 8 directions to the surrounding cells, in clockwise order
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Position { row, col }
+    }
+
+    /// Moves by `offset`, or returns `None` if the result would have a negative coordinate
+    pub fn checked_add(self, offset: Offset) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(offset.row)?,
+            col: self.col.checked_add_signed(offset.col)?,
+        })
+    }
+
+    /// Moves by `offset`, or returns `None` if the result would be outside of a grid of the given
+    /// size
+    pub fn checked_add_within(
+        self,
+        offset: Offset,
+        height: usize,
+        width: usize,
+    ) -> Option<Position> {
+        self.checked_add(offset)
+            .filter(|p| p.row < height && p.col < width)
+    }
+
+    /// The offset that moves `self` to `other`
+    pub fn offset_to(self, other: Position) -> Offset {
+        other - self
+    }
+
+    /// Number of orthogonal steps needed to go from `self` to `other`
+    pub fn manhattan_distance(self, other: Position) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Number of steps needed to go from `self` to `other` when diagonal steps are allowed
+    pub fn chebyshev_distance(self, other: Position) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((row, col): (usize, usize)) -> Self {
+        Position { row, col }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Sub for Position {
+    type Output = Offset;
+
+    fn sub(self, other: Position) -> Offset {
+        Offset {
+            row: self.row as isize - other.row as isize,
+            col: self.col as isize - other.col as isize,
+        }
+    }
+}
+
+impl Offset {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Offset { row, col }
+    }
+
+    pub fn manhattan_length(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+
+    pub fn chebyshev_length(self) -> usize {
+        self.row.unsigned_abs().max(self.col.unsigned_abs())
+    }
+
+    /// Rotates the offset by 90 degrees clockwise (as seen on screen, with rows growing downwards)
+    pub fn rotate_cw(self) -> Offset {
+        Offset {
+            row: self.col,
+            col: -self.row,
+        }
+    }
+
+    /// Rotates the offset by 90 degrees counterclockwise
+    pub fn rotate_ccw(self) -> Offset {
+        Offset {
+            row: -self.col,
+            col: self.row,
+        }
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Offset {
+        Offset {
+            row: self.row + other.row,
+            col: self.col + other.col,
+        }
+    }
+}
+
+impl AddAssign for Offset {
+    fn add_assign(&mut self, other: Offset) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Offset {
+    type Output = Offset;
+
+    fn sub(self, other: Offset) -> Offset {
+        self + -other
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Offset {
+        Offset {
+            row: -self.row,
+            col: -self.col,
+        }
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, factor: isize) -> Offset {
+        Offset {
+            row: self.row * factor,
+            col: self.col * factor,
+        }
+    }
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Offset {
+        match self {
+            Direction::Up => Offset::new(-1, 0),
+            Direction::Right => Offset::new(0, 1),
+            Direction::Down => Offset::new(1, 0),
+            Direction::Left => Offset::new(0, -1),
+        }
+    }
+
+    pub fn rotate_cw(self) -> Direction {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn rotate_ccw(self) -> Direction {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn offset(self) -> Offset {
+        match self {
+            Direction8::Up => Offset::new(-1, 0),
+            Direction8::UpRight => Offset::new(-1, 1),
+            Direction8::Right => Offset::new(0, 1),
+            Direction8::DownRight => Offset::new(1, 1),
+            Direction8::Down => Offset::new(1, 0),
+            Direction8::DownLeft => Offset::new(1, -1),
+            Direction8::Left => Offset::new(0, -1),
+            Direction8::UpLeft => Offset::new(-1, -1),
+        }
+    }
+
+    /// Rotates by 45 degrees clockwise
+    pub fn rotate_cw(self) -> Direction8 {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotates by 45 degrees counterclockwise
+    pub fn rotate_ccw(self) -> Direction8 {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_add() {
+        let p = Position::new(0, 2);
+        assert_eq!(
+            Some(Position::new(1, 2)),
+            p.checked_add(Direction::Down.offset())
+        );
+        assert_eq!(None, p.checked_add(Direction::Up.offset()));
+        assert_eq!(None, p.checked_add(Direction8::UpLeft.offset()));
+        assert_eq!(None, p.checked_add_within(Direction::Right.offset(), 3, 3));
+        assert_eq!(
+            Some(Position::new(1, 1)),
+            p.checked_add_within(Direction8::DownLeft.offset(), 3, 3)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Position::new(1, 5);
+        let b = Position::new(4, 1);
+        assert_eq!(7, a.manhattan_distance(b));
+        assert_eq!(4, a.chebyshev_distance(b));
+        assert_eq!(Offset::new(3, -4), a.offset_to(b));
+        assert_eq!(7, (a - b).manhattan_length());
+        assert_eq!(4, (a - b).chebyshev_length());
+    }
+
+    #[test]
+    fn test_offset_arithmetic() {
+        let mut offset = Offset::new(1, -2);
+        offset += Offset::new(1, 1);
+        assert_eq!(Offset::new(2, -1), offset);
+        assert_eq!(Offset::new(6, -3), offset * 3);
+        assert_eq!(Offset::new(0, 0), offset - offset);
+        assert_eq!(Offset::new(-2, 1), -offset);
+    }
+
+    #[test]
+    fn test_rotations() {
+        assert_eq!(
+            Direction::Right.offset(),
+            Direction::Up.offset().rotate_cw()
+        );
+        assert_eq!(
+            Direction::Left.offset(),
+            Direction::Up.offset().rotate_ccw()
+        );
+        assert_eq!(Direction::Left, Direction::Up.rotate_ccw());
+        assert_eq!(Direction::Up, Direction::Left.rotate_cw());
+        assert_eq!(Direction::Down, Direction::Up.opposite());
+        assert_eq!(Direction8::UpLeft, Direction8::Up.rotate_ccw());
+        assert_eq!(Direction8::DownLeft, Direction8::UpRight.opposite());
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_cw(),
+                direction.rotate_cw().offset()
+            );
+            assert_eq!(direction.offset(), Direction8::from(direction).offset());
+        }
+        for direction in Direction8::ALL {
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+/// Finds all the occurrences of a set of patterns in a text, including overlapping ones (both
+/// `eight` and `two` in `eightwo`), in a single pass over the text.
+///
+/// This is the Aho–Corasick algorithm: the patterns are stored in a trie, and each node also
+/// knows where to go on a mismatch (the longest suffix of its prefix that is also a prefix of some
+/// pattern). The trie is turned into a table of transitions on every byte, so that matching is a
+/// single lookup per byte of text.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    nodes: Vec<Node>,
+    pattern_lens: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    next: [usize; 256],
+    /// Indices of the patterns ending at this node, including through suffix links
+    outputs: Vec<usize>,
+}
+
+/// An occurrence of a pattern: `text[start..end]` is equal to the pattern
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Index of the pattern in the list given to [`Matcher::new`]
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+const ROOT: usize = 0;
+
+impl Node {
+    fn new() -> Self {
+        Node {
+            next: [ROOT; 256],
+            outputs: Vec::new(),
+        }
+    }
+}
+
+impl Matcher {
+    /// Builds a matcher for `patterns`, which are matched byte by byte.
+    ///
+    /// Panics if a pattern is empty.
+    pub fn new<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = P>) -> Self {
+        let mut nodes: Vec<Node> = vec![Node::new()];
+        // Whether a transition of the trie exists, as opposed to one filled in from a suffix link
+        let mut in_trie: Vec<[bool; 256]> = vec![[false; 256]];
+        let mut pattern_lens: Vec<usize> = Vec::new();
+
+        for (index, pattern) in patterns.into_iter().enumerate() {
+            let pattern: &[u8] = pattern.as_ref();
+            assert!(!pattern.is_empty(), "Pattern {} is empty", index);
+            let mut node: usize = ROOT;
+            for &byte in pattern {
+                if !in_trie[node][byte as usize] {
+                    nodes.push(Node::new());
+                    in_trie.push([false; 256]);
+                    in_trie[node][byte as usize] = true;
+                    nodes[node].next[byte as usize] = nodes.len() - 1;
+                }
+                node = nodes[node].next[byte as usize];
+            }
+            nodes[node].outputs.push(index);
+            pattern_lens.push(pattern.len());
+        }
+
+        // Breadth first, so that the suffix link of a node is complete before its children's
+        let mut suffix_links: Vec<usize> = vec![ROOT; nodes.len()];
+        let mut queue: VecDeque<usize> = VecDeque::from([ROOT]);
+        while let Some(node) = queue.pop_front() {
+            for (byte, &exists) in in_trie[node].iter().enumerate() {
+                let child: usize = nodes[node].next[byte];
+                if !exists {
+                    // Missing transitions go where the suffix link would lead
+                    nodes[node].next[byte] = match node {
+                        ROOT => ROOT,
+                        _ => nodes[suffix_links[node]].next[byte],
+                    };
+                    continue;
+                }
+                let link: usize = match node {
+                    ROOT => ROOT,
+                    _ => nodes[suffix_links[node]].next[byte],
+                };
+                suffix_links[child] = link;
+                let inherited: Vec<usize> = nodes[link].outputs.clone();
+                nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        Matcher {
+            nodes,
+            pattern_lens,
+        }
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        self.pattern_lens.len()
+    }
+
+    /// Iterates over all the occurrences of the patterns in `text`, overlapping or not. Matches
+    /// are ordered by end position, and then from the longest to the shortest pattern.
+    pub fn find_overlapping<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        let mut node: usize = ROOT;
+        text.iter().enumerate().flat_map(move |(i, &byte)| {
+            node = self.nodes[node].next[byte as usize];
+            self.nodes[node].outputs.iter().map(move |&pattern| Match {
+                pattern,
+                start: i + 1 - self.pattern_lens[pattern],
+                end: i + 1,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(patterns: &[&str], text: &str) -> Vec<(usize, usize, usize)> {
+        Matcher::new(patterns)
+            .find_overlapping(text.as_bytes())
+            .map(|m| (m.pattern, m.start, m.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_overlapping() {
+        assert_eq!(
+            vec![(0, 0, 5), (1, 4, 7)],
+            find(&["eight", "two", "one"], "eightwo")
+        );
+        assert_eq!(
+            vec![(1, 1, 4), (2, 3, 6), (0, 5, 10)],
+            find(&["eight", "two", "one"], "xtwoneight")
+        );
+    }
+
+    #[test]
+    fn test_nested_patterns() {
+        // "he" and "e" end inside "she", "hers" starts inside it
+        assert_eq!(
+            vec![(1, 1, 4), (0, 2, 4), (3, 3, 4), (2, 2, 6)],
+            find(&["he", "she", "hers", "e"], "ushers")
+        );
+    }
+
+    #[test]
+    fn test_repeated_and_no_match() {
+        assert_eq!(vec![(0, 0, 2), (0, 1, 3), (0, 2, 4)], find(&["aa"], "aaaa"));
+        assert!(find(&["one"], "on").is_empty());
+        assert!(find(&["one"], "").is_empty());
+        assert_eq!(0, Matcher::new(Vec::<&str>::new()).pattern_count());
+    }
+
+    #[test]
+    #[should_panic(expected = "Pattern 1 is empty")]
+    fn test_empty_pattern() {
+        Matcher::new(["a", ""]);
+    }
+}
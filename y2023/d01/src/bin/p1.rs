@@ -1,55 +1,47 @@
-use regex::Regex;
-use std::io;
+use aoc_common::number::Answer;
+use aoc_common::{input, Error, OrExit};
+use std::borrow::Cow;
+use std::env;
+use std::io::BufRead;
 use std::time::{Duration, Instant};
+use y2023_d01::explain;
+use y2023_d01::options::Options;
+use y2023_d01::p1::{self, run, run_reader};
+use y2023_d01::vocabulary::Vocabulary;
+use y2023_d01::{parse, INPUT};
 
 fn main() {
-    let input_text: &str = include_str!("input/input.txt");
-
-    let start: Instant = Instant::now();
-    let result: i64 = run(input_text).expect("Should have worked");
-    let duration: Duration = start.elapsed();
-
-    println!("Result: {}", result);
-    println!("Execution time: {:.2?}", duration);
-}
-
-fn run(input_text: &str) -> io::Result<i64> {
-    let re = Regex::new(r#"\d{1}"#).unwrap();
-    let mut total_sum: i64 = 0;
+    let options: Options = Options::from_args(env::args().skip(1)).or_exit();
+    if options.vocabulary != Vocabulary::default() || options.diagnose {
+        Err::<(), _>(Error::Invalid(
+            "--vocabulary, --zero and --diagnose are only for part 2".to_string(),
+        ))
+        .or_exit();
+    }
 
-    for line in input_text.lines() {
-        let numbers: Vec<i64> = find_numbers(&re, line);
-        let first: &i64 = numbers.first().unwrap();
-        let last: &i64 = numbers.last().unwrap();
+    if options.stream {
+        let reader: Box<dyn BufRead> = input::open(options.path.as_deref(), INPUT).or_exit();
+        let start: Instant = Instant::now();
+        let result: Answer = run_reader(reader).or_exit();
+        let duration: Duration = start.elapsed();
 
-        total_sum += 10 * first + last;
+        println!("Result: {}", result);
+        println!("Execution time: {:.2?}", duration);
+        return;
     }
 
-    Ok(total_sum)
-}
+    let input_text: Cow<str> = input::load(options.path.as_deref(), INPUT).or_exit();
 
-/**
- * Finds single digits in a string
- *
- * See https://stackoverflow.com/questions/58010114/capture-all-regex-matches-into-a-vector
- */
-fn find_numbers(re: &Regex, s: &str) -> Vec<i64> {
-    // Iterate over all matches
-    re.find_iter(s)
-        // Try to parse the string matches as i64 (inferred from fn type signature)
-        // and filter out the matches that can't be parsed (e.g. if there are too many digits to store in an i64).
-        .filter_map(|digits| digits.as_str().parse().ok())
-        // Collect the results in to a Vec<i64> (inferred from fn type signature)
-        .collect()
-}
+    if let Some(format) = options.explain {
+        let explanations = p1::explain(&parse(&input_text).or_exit()).or_exit();
+        print!("{}", explain::render(&explanations, format));
+        return;
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    let start: Instant = Instant::now();
+    let result: Answer = run(&input_text).or_exit();
+    let duration: Duration = start.elapsed();
 
-    #[test]
-    fn test_run() {
-        let test_text: &str = include_str!("input/test1.txt");
-        assert_eq!(142i64, run(test_text).unwrap());
-    }
+    println!("Result: {}", result);
+    println!("Execution time: {:.2?}", duration);
 }
@@ -0,0 +1,130 @@
+use crate::vocabulary::Digit;
+use aoc_common::{Error, Result};
+use serde::Serialize;
+use std::fmt;
+
+/// How the calibration value of a line was found
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    /// 1-based line number
+    pub line: usize,
+    pub text: String,
+    pub value: i64,
+    pub first: Digit,
+    pub last: Digit,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(Error::Invalid(format!(
+                "unknown explain format {:?}, expected text or json",
+                name
+            ))),
+        }
+    }
+}
+
+impl Explanation {
+    pub fn new(line_index: usize, text: &str, first: Digit, last: Digit) -> Self {
+        Explanation {
+            line: line_index + 1,
+            text: text.to_string(),
+            value: 10 * first.value as i64 + last.value as i64,
+            first,
+            last,
+        }
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter, digit: &Digit) -> fmt::Result {
+        write!(
+            f,
+            "{} at bytes {}..{} ",
+            digit.value, digit.start, digit.end
+        )?;
+        match digit.spelled {
+            true => write!(f, "(spelled {:?})", &self.text[digit.start..digit.end]),
+            false => write!(f, "(numeral)"),
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    /// e.g. `line 1 "two1nine": 29, first 2 at bytes 0..3 (spelled "two"), last 9 at bytes 4..8
+    /// (spelled "nine")`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {} {:?}: {}, first ",
+            self.line, self.text, self.value
+        )?;
+        self.describe(f, &self.first)?;
+        write!(f, ", last ")?;
+        self.describe(f, &self.last)
+    }
+}
+
+/// Formats the explanations of all lines, one per line for [`Format::Text`] or as an array for
+/// [`Format::Json`]
+pub fn render(explanations: &[Explanation], format: Format) -> String {
+    match format {
+        Format::Text => explanations
+            .iter()
+            .map(|explanation| format!("{}\n", explanation))
+            .collect(),
+        Format::Json => serde_json::to_string_pretty(explanations).unwrap() + "\n",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explanation() -> Explanation {
+        let digit = |value, start, end, spelled| Digit {
+            value,
+            start,
+            end,
+            spelled,
+        };
+        Explanation::new(0, "two1nine", digit(2, 0, 3, true), digit(1, 3, 4, false))
+    }
+
+    #[test]
+    fn test_render_text() {
+        assert_eq!(
+            "line 1 \"two1nine\": 21, first 2 at bytes 0..3 (spelled \"two\"), last 1 at bytes 3..4 (numeral)\n",
+            render(&[explanation()], Format::Text)
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&[explanation()], Format::Json)).unwrap();
+        assert_eq!(
+            serde_json::json!([{
+                "line": 1,
+                "text": "two1nine",
+                "value": 21,
+                "first": {"value": 2, "start": 0, "end": 3, "spelled": true},
+                "last": {"value": 1, "start": 3, "end": 4, "spelled": false},
+            }]),
+            json
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Format::Json, Format::parse("json").unwrap());
+        assert!(Format::parse("yaml").is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_common::rng::Rng;
+use std::io::{self, Write};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Spelled digits sharing letters, which a non-overlapping search gets wrong
+const OVERLAPPING: [&str; 8] = [
+    "oneight",
+    "twone",
+    "threeight",
+    "fiveight",
+    "sevenine",
+    "eightwo",
+    "eighthree",
+    "nineight",
+];
+
+/// Generates a calibration document of `lines` lines, mixing letters, numerals, spelled digits
+/// and overlapping spelled digits. Every line has at least one numeral, so that both parts can
+/// solve it.
+pub fn generate(rng: &mut Rng, lines: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..lines {
+        let mut tokens: Vec<String> = vec![rng.range(1..10).to_string()];
+        for _ in 0..rng.range(1..6) {
+            tokens.push(match rng.range(0..4) {
+                0 => rng.range(1..10).to_string(),
+                1 => rng.choose(&WORDS).to_string(),
+                2 => rng.choose(&OVERLAPPING).to_string(),
+                _ => (0..rng.range(1..6))
+                    .map(|_| (b'a' + rng.range(0..26) as u8) as char)
+                    .collect(),
+            });
+        }
+        rng.shuffle(&mut tokens);
+        writeln!(out, "{}", tokens.concat())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generated(seed: u64, lines: usize) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        generate(&mut Rng::new(seed), lines, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_generate() {
+        let text: String = generated(1, 200);
+        assert_eq!(text, generated(1, 200));
+        assert_ne!(text, generated(2, 200));
+        assert_eq!(200, text.lines().count());
+        assert!(crate::p1::run(&text).is_ok());
+        assert!(crate::p2::run(&text).is_ok());
+        assert!(!crate::p2::diagnose(&text).unwrap().is_empty());
+    }
+}
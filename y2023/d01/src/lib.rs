@@ -0,0 +1,39 @@
+use aoc_common::number::Answer;
+use aoc_common::{Result, Solution};
+
+pub mod explain;
+pub mod generator;
+pub mod options;
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod properties;
+pub mod vocabulary;
+
+pub const INPUT: &str = include_str!("input/input.txt");
+pub const ANSWERS: &str = include_str!("../answers.toml");
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<String>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(&self, input_text: &str) -> Result<Vec<String>> {
+        parse(input_text)
+    }
+
+    fn part1(&self, lines: &Vec<String>) -> Result<Answer> {
+        p1::solve(lines)
+    }
+
+    fn part2(&self, lines: &Vec<String>) -> Result<Answer> {
+        p2::solve(lines)
+    }
+}
+
+/// Each line of the calibration document is solved on its own, so parsing only splits the lines
+pub fn parse(input_text: &str) -> Result<Vec<String>> {
+    Ok(input_text.lines().map(String::from).collect())
+}
@@ -0,0 +1,124 @@
+use crate::explain::Format;
+use crate::vocabulary::Vocabulary;
+use aoc_common::{Error, Result};
+
+/// Command line options of the day 1 binaries. Only part 2 uses the vocabulary and diagnostics.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Input path, `-` for stdin, or `None` for the bundled input
+    pub path: Option<String>,
+    pub vocabulary: Vocabulary,
+    /// Print the lines where `run_bad` and `run` disagree instead of the answer
+    pub diagnose: bool,
+    /// Print how the value of every line is found instead of the answer
+    pub explain: Option<Format>,
+    /// Read the input line by line instead of loading it all in memory
+    pub stream: bool,
+}
+
+impl Options {
+    /// Reads `--vocabulary NAME|PATH`, `--zero`, `--diagnose`, `--explain text|json`, `--stream`
+    /// and the optional input path. The vocabulary is either a built-in language or a file of
+    /// `<word> <digit>` lines, and `--zero` adds the word for zero of a built-in language.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut args = args.into_iter();
+        let mut path: Option<String> = None;
+        let mut vocabulary: Option<String> = None;
+        let mut zero: bool = false;
+        let mut diagnose: bool = false;
+        let mut explain: Option<Format> = None;
+        let mut stream: bool = false;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--vocabulary" => {
+                    vocabulary = Some(args.next().ok_or_else(|| {
+                        Error::Invalid("missing value for --vocabulary".to_string())
+                    })?)
+                }
+                "--zero" => zero = true,
+                "--diagnose" => diagnose = true,
+                "--stream" => stream = true,
+                "--explain" => {
+                    let format: String = args
+                        .next()
+                        .ok_or_else(|| Error::Invalid("missing value for --explain".to_string()))?;
+                    explain = Some(Format::parse(&format)?);
+                }
+                _ if arg.starts_with("--") => {
+                    return Err(Error::Invalid(format!("unknown option {}", arg)))
+                }
+                _ if path.is_none() => path = Some(arg),
+                _ => return Err(Error::Invalid(format!("unexpected argument {}", arg))),
+            }
+        }
+
+        if stream && (diagnose || explain.is_some()) {
+            return Err(Error::Invalid(
+                "--stream can't be combined with --diagnose or --explain".to_string(),
+            ));
+        }
+        // run_bad, which --diagnose compares with run, only knows the English words
+        if diagnose && (vocabulary.is_some() || zero) {
+            return Err(Error::Invalid(
+                "--diagnose can't be combined with --vocabulary or --zero".to_string(),
+            ));
+        }
+
+        let name: &str = vocabulary.as_deref().unwrap_or("english");
+        let vocabulary: Vocabulary = match Vocabulary::language(name) {
+            Some(language) if zero => language.with_zero(name).unwrap(),
+            Some(language) => language,
+            None if zero => {
+                return Err(Error::Invalid(format!(
+                    "--zero needs one of the built-in vocabularies: {}",
+                    Vocabulary::names().collect::<Vec<_>>().join(", ")
+                )))
+            }
+            None => Vocabulary::load(name)?,
+        };
+
+        Ok(Options {
+            path,
+            vocabulary,
+            diagnose,
+            explain,
+            stream,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Options> {
+        Options::from_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(Options::default(), args(&[]).unwrap());
+        let options = args(&["input.txt", "--vocabulary", "german", "--zero"]).unwrap();
+        assert_eq!(Some("input.txt".to_string()), options.path);
+        assert_eq!(
+            Vocabulary::language("german").unwrap().with_zero("german"),
+            Some(options.vocabulary)
+        );
+        assert!(!options.diagnose);
+        assert!(args(&["--diagnose"]).unwrap().diagnose);
+        assert_eq!(
+            Some(Format::Json),
+            args(&["--explain", "json"]).unwrap().explain
+        );
+        assert!(args(&["--explain"]).is_err());
+        assert!(args(&["--stream"]).unwrap().stream);
+        assert!(args(&["--stream", "--diagnose"]).is_err());
+        assert!(args(&["--diagnose", "--vocabulary", "spanish"]).is_err());
+        assert!(args(&["--diagnose", "--zero"]).is_err());
+        assert!(args(&["--vocabulary"]).is_err());
+        assert!(args(&["--vocabulary", "/does/not/exist.txt"]).is_err());
+        assert!(args(&["--vocabulary", "/does/not/exist.txt", "--zero"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::explain::Explanation;
+use crate::vocabulary::Digit;
+use aoc_common::number::{Answer, Checked};
+use aoc_common::{input, regex, Error, Result};
+use regex::Regex;
+use std::io::BufRead;
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    solve(&crate::parse(input_text)?)
+}
+
+/// Solves the input line by line as it is read, without loading it all in memory
+pub fn run_reader(reader: impl BufRead) -> Result<Answer> {
+    let mut total_sum: Answer = Answer::from(0u32);
+    input::for_each_line(reader, |line_index, line| {
+        let value: u32 = calibration_value(line_index, line)?;
+        total_sum = std::mem::take(&mut total_sum).try_add(Answer::from(value))?;
+        Ok(())
+    })?;
+    Ok(total_sum)
+}
+
+pub fn solve(lines: &[String]) -> Result<Answer> {
+    let mut total_sum: Answer = Answer::from(0u32);
+
+    for (line_index, line) in lines.iter().enumerate() {
+        let value: u32 = calibration_value(line_index, line)?;
+        total_sum = total_sum.try_add(Answer::from(value))?;
+    }
+
+    Ok(total_sum)
+}
+
+fn calibration_value(line_index: usize, line: &str) -> Result<u32> {
+    let re: &Regex = regex!(r#"\d{1}"#);
+    let numbers: Vec<u32> = find_numbers(re, line);
+    let (Some(first), Some(last)) = (numbers.first(), numbers.last()) else {
+        return Err(Error::parse(
+            line_index,
+            1,
+            line,
+            "expected at least one digit",
+        ));
+    };
+    Ok(10 * first + last)
+}
+
+/// Explains how the calibration value of every line is found
+pub fn explain(lines: &[String]) -> Result<Vec<Explanation>> {
+    let re: &Regex = regex!(r#"\d{1}"#);
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let mut digits = re.find_iter(line).filter_map(|m| {
+                Some(Digit {
+                    value: m.as_str().parse().ok()?,
+                    start: m.start(),
+                    end: m.end(),
+                    spelled: false,
+                })
+            });
+            let first: Digit = digits
+                .next()
+                .ok_or_else(|| Error::parse(line_index, 1, line, "expected at least one digit"))?;
+            let last: Digit = digits.last().unwrap_or(first);
+            Ok(Explanation::new(line_index, line, first, last))
+        })
+        .collect()
+}
+
+/**
+ * Finds single digits in a string
+ *
+ * See https://stackoverflow.com/questions/58010114/capture-all-regex-matches-into-a-vector
+ */
+fn find_numbers(re: &Regex, s: &str) -> Vec<u32> {
+    // Iterate over all matches
+    re.find_iter(s)
+        // Try to parse the string matches as u32 (inferred from fn type signature)
+        // and filter out the matches that can't be parsed (e.g. if there are too many digits to store in a u32).
+        .filter_map(|digits| digits.as_str().parse().ok())
+        // Collect the results in to a Vec<u32> (inferred from fn type signature)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test1.txt");
+        assert_eq!(Answer::from(142u32), run(test_text).unwrap());
+    }
+
+    #[test]
+    fn test_run_reader() {
+        let test_text: &str = include_str!("input/test1.txt");
+        assert_eq!(
+            Answer::from(142u32),
+            run_reader(test_text.as_bytes()).unwrap()
+        );
+        assert_eq!(
+            run("1abc2\nabc").unwrap_err().to_string(),
+            run_reader("1abc2\nabc".as_bytes()).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let lines: Vec<String> = crate::parse("a1b2c3d\ntreb7uchet").unwrap();
+        let explanations: Vec<Explanation> = explain(&lines).unwrap();
+        assert_eq!(
+            vec![13, 77],
+            explanations.iter().map(|e| e.value).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            (5, 6),
+            (explanations[0].last.start, explanations[0].last.end)
+        );
+        assert_eq!(explanations[1].first, explanations[1].last);
+        assert!(explain(&["abc".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_run_no_digits() {
+        assert_eq!(
+            "line 2: expected at least one digit (column 1: \"abc\")",
+            run("1abc2\nabc").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use crate::explain::Explanation;
+use crate::vocabulary::{Decoder, Digit, Vocabulary};
+use aoc_common::number::{Answer, Checked};
+use aoc_common::{input, regex, Error, Result};
+use regex::Regex;
+use std::fmt;
+use std::io::BufRead;
+use std::sync::LazyLock;
+
+/**
+ * THIS DOES NOT WORK
+ *
+ * See https://stackoverflow.com/questions/77587365/overlapping-matches-in-regex-rust-regex-engine
+ *
+ * The regex doesn't return overlapping matches, so the last digit is wrong when the line ends with
+ * overlapping words like `eightwo`. Use [`diagnose`] to see the lines where it goes wrong.
+ */
+pub fn run_bad(input_text: &str) -> Result<Answer> {
+    let mut total_sum: Answer = Answer::from(0u32);
+
+    for (line_index, line) in input_text.lines().enumerate() {
+        let value: u32 =
+            bad_calibration_value(line).ok_or_else(|| no_digit_error(line_index, line))?;
+        total_sum = total_sum.try_add(Answer::from(value))?;
+    }
+
+    Ok(total_sum)
+}
+
+fn bad_calibration_value(line: &str) -> Option<u32> {
+    let re: &Regex = regex!(r#"([1-9]|one|two|three|four|five|six|seven|eight|nine){1}"#);
+    let matches: Vec<String> = find_strings(re, line);
+    let (first_match, last_match) = (matches.first()?, matches.last()?);
+    let first_calibration_digit: &str = match_calibration_values(first_match)?;
+    let last_calibration_digit: &str = match_calibration_values(last_match)?;
+    // See https://stackoverflow.com/questions/30154541/how-do-i-concatenate-strings
+    let calibration_val_str: String = first_calibration_digit.to_owned() + last_calibration_digit;
+    Some(calibration_val_str.parse::<u32>().unwrap())
+}
+
+/// A line where [`run_bad`] and [`run`] disagree
+#[derive(Debug, PartialEq, Eq)]
+pub struct Disagreement {
+    pub line_index: usize,
+    pub line: String,
+    pub bad: u32,
+    pub good: u32,
+    /// Pairs of spelled digits sharing letters, e.g. `("eight", "two")` in `eightwo`
+    pub overlaps: Vec<(String, String)>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {} {:?}: run_bad gives {}, run gives {} (overlapping words: {})",
+            self.line_index + 1,
+            self.line,
+            self.bad,
+            self.good,
+            self.overlaps
+                .iter()
+                .map(|(a, b)| format!("{}/{}", a, b))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Runs [`run_bad`] and [`run`] on every line, and returns the lines where they disagree along
+/// with the overlapping words of these lines
+pub fn diagnose(input_text: &str) -> Result<Vec<Disagreement>> {
+    let decoder = Decoder::default();
+    let mut disagreements: Vec<Disagreement> = Vec::new();
+
+    for (line_index, line) in input_text.lines().enumerate() {
+        let (first, last) = decoder
+            .first_and_last_digits(line)
+            .ok_or_else(|| no_digit_error(line_index, line))?;
+        let good: u32 = 10 * first + last;
+        let bad: u32 =
+            bad_calibration_value(line).ok_or_else(|| no_digit_error(line_index, line))?;
+        if bad == good {
+            continue;
+        }
+
+        let words: Vec<Digit> = decoder.digits(line).filter(|d| d.spelled).collect();
+        let overlaps: Vec<(String, String)> = words
+            .iter()
+            .flat_map(|a| words.iter().map(move |b| (a, b)))
+            .filter(|(a, b)| a.start < b.start && b.start < a.end)
+            .map(|(a, b)| {
+                (
+                    line[a.start..a.end].to_string(),
+                    line[b.start..b.end].to_string(),
+                )
+            })
+            .collect();
+        disagreements.push(Disagreement {
+            line_index,
+            line: line.to_string(),
+            bad,
+            good,
+            overlaps,
+        });
+    }
+
+    Ok(disagreements)
+}
+
+fn find_strings(re: &Regex, s: &str) -> Vec<String> {
+    re.find_iter(s)
+        .filter_map(|matches| matches.as_str().parse().ok())
+        .collect()
+}
+
+fn match_calibration_values(str_to_match: &str) -> Option<&str> {
+    Some(match str_to_match {
+        "1" | "one" => "1",
+        "2" | "two" => "2",
+        "3" | "three" => "3",
+        "4" | "four" => "4",
+        "5" | "five" => "5",
+        "6" | "six" => "6",
+        "7" | "seven" => "7",
+        "8" | "eight" => "8",
+        "9" | "nine" => "9",
+        _ => return None,
+    })
+}
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    solve(&crate::parse(input_text)?)
+}
+
+/// Solves a variant of the puzzle where the digits may be spelled with another vocabulary
+pub fn run_with(input_text: &str, vocabulary: &Vocabulary) -> Result<Answer> {
+    solve_with(&crate::parse(input_text)?, &Decoder::new(vocabulary))
+}
+
+pub fn solve(lines: &[String]) -> Result<Answer> {
+    static DECODER: LazyLock<Decoder> = LazyLock::new(Decoder::default);
+    solve_with(lines, &DECODER)
+}
+
+pub fn solve_with(lines: &[String], decoder: &Decoder) -> Result<Answer> {
+    let mut total_sum: Answer = Answer::from(0u32);
+
+    for (line_index, line) in lines.iter().enumerate() {
+        let value: u32 = calibration_value(decoder, line_index, line)?;
+        total_sum = total_sum.try_add(Answer::from(value))?;
+    }
+
+    Ok(total_sum)
+}
+
+/// Solves the input line by line as it is read, without loading it all in memory
+pub fn run_reader_with(reader: impl BufRead, vocabulary: &Vocabulary) -> Result<Answer> {
+    let decoder = Decoder::new(vocabulary);
+    let mut total_sum: Answer = Answer::from(0u32);
+    input::for_each_line(reader, |line_index, line| {
+        let value: u32 = calibration_value(&decoder, line_index, line)?;
+        total_sum = std::mem::take(&mut total_sum).try_add(Answer::from(value))?;
+        Ok(())
+    })?;
+    Ok(total_sum)
+}
+
+fn calibration_value(decoder: &Decoder, line_index: usize, line: &str) -> Result<u32> {
+    let (first, last) = decoder
+        .first_and_last_digits(line)
+        .ok_or_else(|| no_digit_error(line_index, line))?;
+    Ok(10 * first + last)
+}
+
+/// Explains how the calibration value of every line is found
+pub fn explain_with(lines: &[String], decoder: &Decoder) -> Result<Vec<Explanation>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(line_index, line)| {
+            let (first, last) = decoder
+                .first_and_last(line)
+                .ok_or_else(|| no_digit_error(line_index, line))?;
+            Ok(Explanation::new(line_index, line, first, last))
+        })
+        .collect()
+}
+
+fn no_digit_error(line_index: usize, line: &str) -> Error {
+    Error::parse(
+        line_index,
+        1,
+        line,
+        "expected at least one digit or spelled number",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test2.txt");
+        assert_eq!(Answer::from(281u32), run(test_text).unwrap());
+    }
+
+    #[test]
+    fn test_run_reader_with() {
+        let test_text: &str = include_str!("input/test2.txt");
+        assert_eq!(
+            Answer::from(281u32),
+            run_reader_with(test_text.as_bytes(), &Vocabulary::default()).unwrap()
+        );
+        assert!(run_reader_with("abc".as_bytes(), &Vocabulary::default()).is_err());
+    }
+
+    #[test]
+    fn test_run_with() {
+        let spanish = Vocabulary::language("spanish").unwrap();
+        assert_eq!(
+            Answer::from(32u32 + 77),
+            run_with(
+                "xtresdosx
+siete",
+                &spanish
+            )
+            .unwrap()
+        );
+        assert!(run_with("one", &spanish).is_err());
+    }
+
+    #[test]
+    fn test_explain_with() {
+        let lines: Vec<String> = crate::parse("two1nine\n7pqrstsixteen").unwrap();
+        let explanations: Vec<Explanation> = explain_with(&lines, &Decoder::default()).unwrap();
+        assert_eq!(
+            vec![29, 76],
+            explanations.iter().map(|e| e.value).collect::<Vec<_>>()
+        );
+        assert!(explanations[0].first.spelled);
+        assert!(!explanations[1].first.spelled);
+        assert_eq!(
+            (6, 9),
+            (explanations[1].last.start, explanations[1].last.end)
+        );
+    }
+
+    #[test]
+    fn test_run_no_digits() {
+        assert_eq!(
+            "line 1: expected at least one digit or spelled number (column 1: \"abc\")",
+            run("abc\n1").unwrap_err().to_string()
+        );
+        assert!(run_bad("abc\n1").is_err());
+    }
+
+    #[test]
+    fn test_run_bad() {
+        // run_bad happens to be right on the example, where no line ends with overlapping words
+        let test_text: &str = include_str!("input/test2.txt");
+        assert_eq!(Answer::from(281u32), run_bad(test_text).unwrap());
+        assert!(diagnose(test_text).unwrap().is_empty());
+
+        // On the real input, it's only wrong on lines with overlapping words
+        let disagreements: Vec<Disagreement> = diagnose(crate::INPUT).unwrap();
+        assert!(!disagreements.is_empty());
+        assert!(disagreements.iter().all(|d| !d.overlaps.is_empty()));
+        // run_bad - run = sum of bad - sum of good, without going negative
+        let bad_sum: u32 = disagreements.iter().map(|d| d.bad).sum();
+        let good_sum: u32 = disagreements.iter().map(|d| d.good).sum();
+        assert_eq!(
+            run_bad(crate::INPUT).unwrap() + Answer::from(good_sum),
+            run(crate::INPUT).unwrap() + Answer::from(bad_sum)
+        );
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let disagreements: Vec<Disagreement> =
+            diagnose("1eightwo\n2two\ntwoneight\n3twone").unwrap();
+        assert_eq!(
+            vec![
+                Disagreement {
+                    line_index: 0,
+                    line: "1eightwo".to_string(),
+                    bad: 18,
+                    good: 12,
+                    overlaps: vec![("eight".to_string(), "two".to_string())],
+                },
+                Disagreement {
+                    line_index: 3,
+                    line: "3twone".to_string(),
+                    bad: 32,
+                    good: 31,
+                    overlaps: vec![("two".to_string(), "one".to_string())],
+                },
+            ],
+            disagreements
+        );
+        assert_eq!(
+            "line 1 \"1eightwo\": run_bad gives 18, run gives 12 (overlapping words: eight/two)",
+            disagreements[0].to_string()
+        );
+    }
+
+    #[test]
+    fn test_run_bad_ignores_other_digits() {
+        // Neither 0 nor non-ASCII digits are calibration digits, for run_bad as for run
+        assert_eq!(Answer::from(33u32), run_bad("30").unwrap());
+        assert_eq!(Answer::from(55u32), run_bad("٣5").unwrap());
+        assert!(diagnose("30\n٣5").unwrap().is_empty());
+        assert!(run_bad("0").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+//! Checks the solutions against naive reference implementations on random documents
+
+use crate::vocabulary::Vocabulary;
+use crate::{p1, p2};
+use aoc_common::number::Answer;
+use proptest::prelude::*;
+
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Part 1 by definition: the first and last characters of the line that are digits
+fn reference_p1(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            let digits: Vec<u32> = line.chars().filter_map(|ch| ch.to_digit(10)).collect();
+            10 * digits[0] + digits[digits.len() - 1]
+        })
+        .sum()
+}
+
+/// The digit starting at byte `offset` of `line`, written as a numeral or spelled in English
+fn digit_at(line: &str, offset: usize) -> Option<u32> {
+    let rest: &str = &line[offset..];
+    (1..=9).find(|&value| {
+        rest.starts_with(char::from(b'0' + value as u8))
+            || rest.starts_with(SPELLED[value as usize - 1])
+    })
+}
+
+/// Part 2 by definition: try every offset from the start for the first digit and every offset
+/// from the end for the last one
+fn reference_p2(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            let first = (0..line.len()).find_map(|offset| digit_at(line, offset));
+            let last = (0..line.len())
+                .rev()
+                .find_map(|offset| digit_at(line, offset));
+            10 * first.unwrap() + last.unwrap()
+        })
+        .sum()
+}
+
+/// Lines made of numerals, spelled digits (some of them sharing letters, like "eightwo") and other
+/// letters, with at least one numeral so that both parts can solve them
+fn line() -> impl Strategy<Value = String> {
+    let token = prop_oneof![
+        (1..=9u8).prop_map(|value| value.to_string()),
+        (0..9usize).prop_map(|index| SPELLED[index].to_string()),
+        prop::sample::select(vec!["oneight", "twone", "eightwo", "threeight", "nineight"])
+            .prop_map(String::from),
+        "[a-z]{1,3}",
+    ];
+    (
+        prop::collection::vec(token.clone(), 0..5),
+        1..=9u8,
+        prop::collection::vec(token, 0..5),
+    )
+        .prop_map(|(before, numeral, after)| {
+            format!("{}{numeral}{}", before.concat(), after.concat())
+        })
+}
+
+fn document() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(line(), 1..20)
+}
+
+proptest! {
+    #[test]
+    fn p1_agrees_with_reference(lines in document()) {
+        prop_assert_eq!(p1::solve(&lines).unwrap(), Answer::from(reference_p1(&lines)));
+    }
+
+    #[test]
+    fn p2_agrees_with_reference(lines in document()) {
+        prop_assert_eq!(p2::solve(&lines).unwrap(), Answer::from(reference_p2(&lines)));
+    }
+
+    #[test]
+    fn p2_paths_agree(lines in document()) {
+        let input_text: String = lines.join("\n");
+        let expected: Answer = p2::solve(&lines).unwrap();
+        prop_assert_eq!(&p2::run(&input_text).unwrap(), &expected);
+        prop_assert_eq!(
+            p2::run_reader_with(input_text.as_bytes(), &Vocabulary::default()).unwrap(),
+            expected
+        );
+        prop_assert_eq!(p1::run_reader(input_text.as_bytes()).unwrap(), p1::run(&input_text).unwrap());
+    }
+}
@@ -0,0 +1,277 @@
+use aoc_common::{parse, Error, Result};
+use aoc_matcher::{Match, Matcher};
+use serde::Serialize;
+use std::fs;
+
+/// Spelled digits from zero to nine of the built-in languages
+const LANGUAGES: [(&str, [&str; 10]); 4] = [
+    (
+        "english",
+        [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ],
+    ),
+    (
+        "spanish",
+        [
+            "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ],
+    ),
+    (
+        "french",
+        [
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ],
+    ),
+    (
+        "german",
+        [
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ],
+    ),
+];
+
+/// The spelled digits a calibration line may contain, with their values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u8)>,
+}
+
+impl Default for Vocabulary {
+    /// English, without zero, as in the puzzle
+    fn default() -> Self {
+        Self::language("english").unwrap()
+    }
+}
+
+impl Vocabulary {
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        LANGUAGES.iter().map(|(name, _)| *name)
+    }
+
+    /// The words for one to nine of a built-in language
+    pub fn language(name: &str) -> Option<Self> {
+        let (_, words) = LANGUAGES.iter().find(|(n, _)| *n == name)?;
+        Some(Vocabulary {
+            words: (1..)
+                .zip(&words[1..])
+                .map(|(v, w)| (w.to_string(), v))
+                .collect(),
+        })
+    }
+
+    /// Adds the word for zero of a built-in language
+    pub fn with_zero(mut self, name: &str) -> Option<Self> {
+        let (_, words) = LANGUAGES.iter().find(|(n, _)| *n == name)?;
+        self.words.push((words[0].to_string(), 0));
+        Some(self)
+    }
+
+    /// Parses a custom vocabulary, one `<word> <digit>` pair per line. Empty lines and lines
+    /// starting with `#` are ignored.
+    pub fn parse(vocabulary_text: &str) -> Result<Self> {
+        let mut words: Vec<(String, u8)> = Vec::new();
+        for (line_index, line) in vocabulary_text.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            let mut fields = parse::fields(line);
+            let (Some((word_column, word)), Some((value_column, value_str)), None) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(Error::parse(
+                    line_index,
+                    1,
+                    line,
+                    "expected \"<word> <digit>\"",
+                ));
+            };
+            let value: u8 = match value_str.parse::<u8>() {
+                Ok(value) if value <= 9 => value,
+                _ => {
+                    return Err(Error::parse(
+                        line_index,
+                        value_column,
+                        value_str,
+                        "expected a digit",
+                    ))
+                }
+            };
+            if words.iter().any(|(w, _)| w == word) {
+                return Err(Error::parse(
+                    line_index,
+                    word_column,
+                    word,
+                    "word already defined",
+                ));
+            }
+            words.push((word.to_string(), value));
+        }
+        Ok(Vocabulary { words })
+    }
+
+    pub fn load(path: &str) -> Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u8)> {
+        self.words.iter().map(|(w, v)| (w.as_str(), *v))
+    }
+}
+
+/// A digit found in a calibration line
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub struct Digit {
+    pub value: u8,
+    /// Byte offsets of the digit in the line
+    pub start: usize,
+    pub end: usize,
+    /// Whether the digit is a spelled word rather than a numeral
+    pub spelled: bool,
+}
+
+/// Finds the digits of a calibration line: the numerals and the words of a [`Vocabulary`]. The
+/// numeral `0` only counts if the vocabulary has a word for zero.
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    matcher: Matcher,
+    /// Value of each pattern of the matcher
+    values: Vec<u8>,
+    /// The patterns before this index are numerals, the others are words
+    numerals: usize,
+}
+
+impl Decoder {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let has_zero: bool = vocabulary.words().any(|(_, v)| v == 0);
+        let numerals: Vec<(String, u8)> = (u8::from(!has_zero)..=9)
+            .map(|v| (v.to_string(), v))
+            .collect();
+        let numerals_count: usize = numerals.len();
+        let (patterns, values): (Vec<String>, Vec<u8>) = numerals
+            .into_iter()
+            .chain(vocabulary.words().map(|(w, v)| (w.to_string(), v)))
+            .unzip();
+        Decoder {
+            matcher: Matcher::new(patterns),
+            values,
+            numerals: numerals_count,
+        }
+    }
+
+    /// All the digits of the line, including overlapping spelled ones, ordered by end offset
+    pub fn digits<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Digit> + 'a {
+        self.matcher
+            .find_overlapping(line.as_bytes())
+            .map(|m: Match| Digit {
+                value: self.values[m.pattern],
+                start: m.start,
+                end: m.end,
+                spelled: m.pattern >= self.numerals,
+            })
+    }
+
+    /// The digits starting first and last in the line. Spelled digits may overlap, so in
+    /// `eightwo` the first digit is 8 and the last one is 2.
+    pub fn first_and_last(&self, line: &str) -> Option<(Digit, Digit)> {
+        let mut digits = self.digits(line);
+        let first_digit: Digit = digits.next()?;
+        Some(digits.fold((first_digit, first_digit), |(first, last), d| {
+            (
+                if d.start < first.start { d } else { first },
+                if d.start >= last.start { d } else { last },
+            )
+        }))
+    }
+
+    /// The values of the digits starting first and last in the line
+    pub fn first_and_last_digits(&self, line: &str) -> Option<(u32, u32)> {
+        let (first, last) = self.first_and_last(line)?;
+        Some((u32::from(first.value), u32::from(last.value)))
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder::new(&Vocabulary::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_and_last_digits() {
+        let decoder = Decoder::default();
+        let digits = |line: &str| decoder.first_and_last_digits(line);
+        assert_eq!(Some((8, 2)), digits("eightwo"));
+        assert_eq!(Some((2, 8)), digits("xtwone3eightwx"));
+        assert_eq!(Some((7, 7)), digits("ab7cd"));
+        assert_eq!(Some((1, 8)), digits("oneight"));
+        assert_eq!(None, digits("zero0"));
+        assert_eq!(
+            Some((
+                Digit {
+                    value: 1,
+                    start: 1,
+                    end: 2,
+                    spelled: false
+                },
+                Digit {
+                    value: 2,
+                    start: 6,
+                    end: 9,
+                    spelled: true
+                }
+            )),
+            decoder.first_and_last("x1eightwo")
+        );
+    }
+
+    #[test]
+    fn test_languages() {
+        let digits = |name: &str, line: &str| {
+            Decoder::new(&Vocabulary::language(name).unwrap()).first_and_last_digits(line)
+        };
+        assert_eq!(Some((3, 2)), digits("spanish", "xtresdosx"));
+        assert_eq!(Some((9, 6)), digits("french", "neufsix"));
+        assert_eq!(Some((5, 1)), digits("german", "fünfeins"));
+        assert_eq!(None, digits("german", "one"));
+        assert!(Vocabulary::language("klingon").is_none());
+
+        let zero = Vocabulary::default().with_zero("english").unwrap();
+        assert_eq!(
+            Some((0, 0)),
+            Decoder::new(&zero).first_and_last_digits("a0zero")
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let vocabulary = Vocabulary::parse("# Made up\nwan 1\n\n  tu 2\nnil 0\n").unwrap();
+        assert_eq!(
+            vec![("wan", 1), ("tu", 2), ("nil", 0)],
+            vocabulary.words().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some((0, 2)),
+            Decoder::new(&vocabulary).first_and_last_digits("0wantu")
+        );
+
+        let error = |text: &str| Vocabulary::parse(text).unwrap_err().to_string();
+        assert_eq!(
+            "line 2: expected \"<word> <digit>\" (column 1: \"tu\")",
+            error("wan 1\ntu")
+        );
+        assert_eq!("line 1: expected a digit (column 5: \"x\")", error("wan x"));
+        assert_eq!(
+            "line 1: expected a digit (column 5: \"12\")",
+            error("wan 12")
+        );
+        assert_eq!(
+            "line 2: word already defined (column 1: \"wan\")",
+            error("wan 1\nwan 2")
+        );
+    }
+}
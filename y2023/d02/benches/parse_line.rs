@@ -0,0 +1,55 @@
+//! Compares the former parsing of a game record, which compiled four regexes on every line, with
+//! the hand-written parser of [`common::game_from_string`]. Run it with `cargo bench -p y2023-d02`.
+
+use aoc_common::bench::{measure, BenchConfig, Stats};
+use regex::Regex;
+use y2023_d02::bag::Bag;
+use y2023_d02::common;
+
+/// A game record of 2023 day 2
+const LINE: &str =
+    "Game 2: 5 green, 4 red, 7 blue; 7 red, 4 green, 4 blue; 8 green, 11 blue, 4 red; \
+    2 red, 18 blue, 3 green; 7 red, 15 blue";
+
+/// The former `draws_from_string`, returning the red, green and blue counts of every draw
+fn draws_from_string_regex(draws_str: &str) -> Vec<(i32, i32, i32)> {
+    let index_regex: Regex = Regex::new(r#"Game \d+:"#).unwrap();
+    let blue_regex = Regex::new(r#"(?<blue>\d+) blue"#).unwrap();
+    let red_regex = Regex::new(r#"(?<red>\d+) red"#).unwrap();
+    let green_regex = Regex::new(r#"(?<green>\d+) green"#).unwrap();
+    let count = |regex: &Regex, draw: &str, colour: &str| match regex.captures(draw) {
+        Some(caps) => caps[colour].parse::<i32>().unwrap(),
+        None => 0_i32,
+    };
+    index_regex
+        .replace_all(draws_str, "")
+        .split(';')
+        .map(|draw| {
+            (
+                count(&red_regex, draw, "red"),
+                count(&green_regex, draw, "green"),
+                count(&blue_regex, draw, "blue"),
+            )
+        })
+        .collect()
+}
+
+fn report(name: &str, stats: Stats) {
+    println!(
+        "{:<10} {:>10.2?} {:>10.2?} {:>10.2?}",
+        name, stats.min, stats.median, stats.p95
+    );
+}
+
+fn main() {
+    let config = BenchConfig::default();
+    let bag = Bag::default();
+
+    println!("Cost of parsing one line ({} characters)", LINE.len());
+    println!("{:<10} {:>10} {:>10} {:>10}", "", "Min", "Median", "P95");
+    report("regex", measure(config, || draws_from_string_regex(LINE)));
+    report(
+        "parser",
+        measure(config, || common::game_from_string(LINE, 0, &bag)),
+    );
+}
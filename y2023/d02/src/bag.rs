@@ -0,0 +1,151 @@
+use crate::common::Draw;
+use aoc_common::number::{self, Answer, Checked};
+use aoc_common::{Error, Result};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use toml::{Table, Value};
+
+/// The cubes loaded in the bag: how many of each colour. The colours of the bag are also the only
+/// ones a game can draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    limits: BTreeMap<String, u32>,
+}
+
+impl Default for Bag {
+    /// The bag of the puzzle: 12 red cubes, 13 green cubes and 14 blue cubes
+    fn default() -> Self {
+        Bag {
+            limits: BTreeMap::from([
+                ("red".to_string(), 12),
+                ("green".to_string(), 13),
+                ("blue".to_string(), 14),
+            ]),
+        }
+    }
+}
+
+impl Bag {
+    pub fn empty() -> Self {
+        Bag {
+            limits: BTreeMap::new(),
+        }
+    }
+
+    /// Parses a bag from TOML, one `colour = count` entry per colour
+    pub fn parse(bag_text: &str) -> Result<Self> {
+        let table: Table = bag_text
+            .parse()
+            .map_err(|e| Error::Invalid(format!("invalid bag: {}", e)))?;
+        let mut bag = Bag::empty();
+        for (colour, value) in table {
+            let count = match value {
+                Value::Integer(count) => u32::try_from(count).ok(),
+                _ => None,
+            }
+            .ok_or_else(|| Error::Invalid(format!("invalid number of {} cubes", colour)))?;
+            bag.set(&colour, count)?;
+        }
+        Ok(bag)
+    }
+
+    pub fn load(path: &str) -> Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Sets the number of cubes of a colour, adding the colour to the bag if needed
+    pub fn set(&mut self, colour: &str, count: u32) -> Result<()> {
+        if colour.is_empty() || !colour.chars().all(char::is_alphabetic) {
+            return Err(Error::Invalid(format!("invalid colour {:?}", colour)));
+        }
+        self.limits.insert(colour.to_string(), count);
+        Ok(())
+    }
+
+    /// Raises the number of cubes of a colour to at least `count`
+    pub(crate) fn raise(&mut self, colour: &str, count: u32) {
+        let limit: &mut u32 = self.limits.entry(colour.to_string()).or_default();
+        *limit = (*limit).max(count);
+    }
+
+    /// Number of cubes of a colour, 0 if the colour is not in the bag
+    pub fn count(&self, colour: &str) -> u32 {
+        self.limits.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Number of cubes of all colours, which may not fit in a `u32`
+    pub fn total(&self) -> Result<Answer> {
+        number::sum(self.limits.values().map(|&count| Answer::from(count)))
+    }
+
+    /// The product of the cubes of every colour of `palette`, computed in `T`
+    pub fn power<T: Checked + From<u8> + From<u32>>(&self, palette: &Bag) -> Result<T> {
+        number::product(palette.colours().map(|colour| T::from(self.count(colour))))
+    }
+
+    pub fn contains(&self, colour: &str) -> bool {
+        self.limits.contains_key(colour)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.limits.keys().map(String::as_str)
+    }
+
+    /// Whether all the cubes of `other` fit in this bag
+    pub fn holds(&self, other: &Bag) -> bool {
+        other
+            .limits
+            .iter()
+            .all(|(colour, &count)| self.limits.get(colour).is_some_and(|&limit| count <= limit))
+    }
+
+    /// Whether the draw could have been taken from this bag
+    pub fn allows(&self, draw: &Draw) -> bool {
+        draw.cubes
+            .iter()
+            .all(|(colour, &count)| self.limits.get(colour).is_some_and(|&limit| count <= limit))
+    }
+}
+
+impl fmt::Display for Bag {
+    /// Formats the bag like a draw, e.g. `14 blue, 13 green, 12 red`
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (colour, count)) in self.limits.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, colour)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let bag = Bag::parse("red = 1\npurple = 7\n").unwrap();
+        assert_eq!(vec!["purple", "red"], bag.colours().collect::<Vec<_>>());
+        assert!(Bag::parse("red = -1").is_err());
+        assert!(Bag::parse("red = \"many\"").is_err());
+        assert!(Bag::parse("\"dark red\" = 1").is_err());
+    }
+
+    #[test]
+    fn test_raise() {
+        let mut bag = Bag::empty();
+        bag.raise("red", 3);
+        bag.raise("blue", 2);
+        bag.raise("red", 1);
+        assert_eq!(3, bag.count("red"));
+        assert_eq!(0, bag.count("green"));
+        assert_eq!(Answer::from(5u32), bag.total().unwrap());
+        assert_eq!("2 blue, 3 red", bag.to_string());
+        assert!(Bag::default().holds(&bag));
+        bag.raise("purple", 1);
+        assert!(!Bag::default().holds(&bag));
+    }
+}
@@ -1,49 +1,45 @@
-use std::io;
+use aoc_common::number::Answer;
+use aoc_common::{input, OrExit};
+use std::borrow::Cow;
+use std::env;
+use std::io::BufRead;
 use std::time::{Duration, Instant};
-mod common;
+use y2023_d02::options::Options;
+use y2023_d02::p1::{run_reader, run_with};
+use y2023_d02::stats::Stats;
+use y2023_d02::{parse_with, INPUT};
 
 fn main() {
-    let input_text = include_str!("input/input.txt");
-
-    let start: Instant = Instant::now();
-    let result: i32 = run(input_text).expect("Should have worked");
-    let duration: Duration = start.elapsed();
-
-    println!("Result: {}", result);
-    println!("Execution time: {:.2?}", duration);
-}
-
-trait IsValid {
-    fn is_valid(&self) -> bool;
-}
-
-impl IsValid for common::Draw {
-    fn is_valid(&self) -> bool {
-        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    let Options {
+        path,
+        bag,
+        stats,
+        stream,
+    } = Options::from_args(env::args().skip(1)).or_exit();
+
+    if stream {
+        let reader: Box<dyn BufRead> = input::open(path.as_deref(), INPUT).or_exit();
+        let start: Instant = Instant::now();
+        let result: Answer = run_reader(reader, &bag).or_exit();
+        let duration: Duration = start.elapsed();
+
+        println!("Result: {}", result);
+        println!("Execution time: {:.2?}", duration);
+        return;
     }
-}
 
-fn run(input_text: &str) -> io::Result<i32> {
-    let mut total_sum: i32 = 0;
+    let input_text: Cow<str> = input::load(path.as_deref(), INPUT).or_exit();
 
-    for (current_index, line) in input_text.lines().enumerate() {
-        let draws_vec: Vec<common::Draw> =
-            common::draws_from_string(line, current_index as i32 + 1);
-        if draws_vec.iter().all(|d| d.is_valid()) {
-            total_sum += draws_vec.first().unwrap().game_id;
-        }
+    if stats {
+        let games = parse_with(&input_text, &bag).or_exit();
+        print!("{}", Stats::compute(&games, &bag).or_exit());
+        return;
     }
 
-    Ok(total_sum)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    let start: Instant = Instant::now();
+    let result: Answer = run_with(&input_text, &bag).or_exit();
+    let duration: Duration = start.elapsed();
 
-    #[test]
-    fn test_run() {
-        let test_text: &str = include_str!("input/test.txt");
-        assert_eq!(8i32, run(test_text).unwrap());
-    }
+    println!("Result: {}", result);
+    println!("Execution time: {:.2?}", duration);
 }
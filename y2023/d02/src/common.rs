@@ -0,0 +1,194 @@
+use crate::bag::Bag;
+use aoc_common::number::Checked;
+use aoc_common::{parse, Error, Result};
+use std::collections::BTreeMap;
+
+/// The cubes shown in a draw, by colour. Colours that weren't shown are absent.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Draw {
+    pub cubes: BTreeMap<String, u32>,
+}
+
+impl Draw {
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    /// The smallest bag the game could have been played with: the most cubes of each colour shown
+    /// in any draw. Computed in a single pass over the draws.
+    pub fn minimum_bag(&self) -> Bag {
+        let mut bag = Bag::empty();
+        for draw in &self.draws {
+            for (colour, &count) in &draw.cubes {
+                bag.raise(colour, count);
+            }
+        }
+        bag
+    }
+
+    /// The product of the cubes needed of every colour of `palette`, computed in `T`
+    pub fn power<T: Checked + From<u8> + From<u32>>(&self, palette: &Bag) -> Result<T> {
+        self.minimum_bag().power(palette)
+    }
+}
+
+/// Parses all games, which may only draw colours from `bag`
+pub fn games_from_string(input_text: &str, bag: &Bag) -> Result<Vec<Game>> {
+    input_text
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| game_from_string(line, line_index, bag))
+        .collect()
+}
+
+/// Parses a line like `Game 3: 8 green, 6 blue; 5 blue, 4 red`
+pub fn game_from_string(line: &str, line_index: usize, bag: &Bag) -> Result<Game> {
+    let header_error = || Error::parse(line_index, 1, line, "expected \"Game <id>: <draws>\"");
+    let rest: &str = line.strip_prefix("Game ").ok_or_else(header_error)?;
+    let (id_str, draws_str) = rest.split_once(':').ok_or_else(header_error)?;
+    let id: u32 = parse::field(
+        line_index,
+        (parse::column(line, id_str), id_str),
+        "expected a game ID",
+    )?;
+
+    let draws = draws_str
+        .split(';')
+        .map(|draw_str| draw_from_string(line, draw_str, line_index, bag))
+        .collect::<Result<Vec<Draw>>>()?;
+
+    Ok(Game { id, draws })
+}
+
+/// Parses a single draw like `3 blue, 4 red`. `line` is the whole line `draw_str` was sliced from,
+/// to report errors at the right column.
+fn draw_from_string(line: &str, draw_str: &str, line_index: usize, bag: &Bag) -> Result<Draw> {
+    let mut draw = Draw::default();
+
+    for cubes in draw_str.split(',') {
+        let mut fields = parse::fields(cubes).map(|(_, f)| (parse::column(line, f), f));
+        let (Some(count), Some((colour_column, colour)), None) =
+            (fields.next(), fields.next(), fields.next())
+        else {
+            let column = parse::column(line, cubes.trim_start());
+            return Err(Error::parse(
+                line_index,
+                column,
+                cubes.trim(),
+                "expected \"<count> <colour>\"",
+            ));
+        };
+        let count: u32 = parse::field(line_index, count, "expected a number of cubes")?;
+        if !bag.contains(colour) {
+            return Err(Error::parse(
+                line_index,
+                colour_column,
+                colour,
+                "unknown colour",
+            ));
+        }
+        if draw.cubes.insert(colour.to_string(), count).is_some() {
+            return Err(Error::parse(
+                line_index,
+                colour_column,
+                colour,
+                "colour already drawn",
+            ));
+        }
+    }
+
+    Ok(draw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(line: &str) -> String {
+        game_from_string(line, 2, &Bag::default())
+            .unwrap_err()
+            .to_string()
+    }
+
+    fn draw(cubes: &[(&str, u32)]) -> Draw {
+        Draw {
+            cubes: cubes.iter().map(|&(c, n)| (c.to_string(), n)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_game_from_string() {
+        assert_eq!(
+            Game {
+                id: 42,
+                draws: vec![draw(&[("blue", 3), ("red", 4)]), draw(&[("green", 2)])],
+            },
+            game_from_string("Game 42: 3 blue, 4 red; 2 green", 0, &Bag::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_game_from_string_errors() {
+        assert_eq!(
+            "line 3: expected \"Game <id>: <draws>\" (column 1: \"Gme 3: 1 red\")",
+            error("Gme 3: 1 red")
+        );
+        assert_eq!(
+            "line 3: expected a game ID (column 6: \"x\")",
+            error("Game x: 1 red")
+        );
+        assert_eq!(
+            "line 3: unknown colour (column 19: \"purple\")",
+            error("Game 3: 3 blue; 4 purple")
+        );
+        assert_eq!(
+            "line 3: colour already drawn (column 26: \"blue\")",
+            error("Game 3: 3 blue, 4 red, 1 blue")
+        );
+        assert_eq!(
+            "line 3: expected a number of cubes (column 17: \"four\")",
+            error("Game 3: 3 blue; four red")
+        );
+        assert_eq!(
+            "line 3: expected a number of cubes (column 24: \"99999999999\")",
+            error("Game 3: 3 blue, 4 red; 99999999999 red")
+        );
+        assert_eq!(
+            "line 3: expected \"<count> <colour>\" (column 16: \"\")",
+            error("Game 3: 3 blue;")
+        );
+    }
+
+    #[test]
+    fn test_game_from_string_palette() {
+        let mut bag = Bag::default();
+        bag.set("purple", 1).unwrap();
+        let game = game_from_string("Game 1: 4 purple, 1 red", 0, &bag).unwrap();
+        assert_eq!(vec![draw(&[("purple", 4), ("red", 1)])], game.draws);
+        assert_eq!(4, game.draws[0].count("purple"));
+        assert_eq!(0, game.draws[0].count("blue"));
+    }
+
+    #[test]
+    fn test_minimum_bag() {
+        let game = game_from_string(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            0,
+            &Bag::default(),
+        )
+        .unwrap();
+        assert_eq!("6 blue, 2 green, 4 red", game.minimum_bag().to_string());
+        assert_eq!(48u64, game.power(&Bag::default()).unwrap());
+        let mut bag = Bag::default();
+        bag.set("purple", 1).unwrap();
+        assert_eq!(0u64, game.power(&bag).unwrap());
+    }
+}
@@ -0,0 +1,49 @@
+use aoc_common::rng::Rng;
+use std::io::{self, Write};
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// Generates `lines` games with 1 to 6 draws each. A draw shows 1 to 3 distinct colours, with up
+/// to 20 cubes of each, so that some games are impossible with the bag of the puzzle.
+pub fn generate(rng: &mut Rng, lines: usize, out: &mut dyn Write) -> io::Result<()> {
+    for id in 1..=lines {
+        let draws: Vec<String> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colours: Vec<&str> = COLOURS.to_vec();
+                rng.shuffle(&mut colours);
+                colours.truncate(rng.range(1..4) as usize);
+                colours
+                    .iter()
+                    .map(|colour| format!("{} {}", rng.range(1..21), colour))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        writeln!(out, "Game {}: {}", id, draws.join("; "))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::number::Answer;
+
+    fn generated(seed: u64, lines: usize) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        generate(&mut Rng::new(seed), lines, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_generate() {
+        let text: String = generated(1, 200);
+        assert_eq!(text, generated(1, 200));
+        assert_ne!(text, generated(2, 200));
+        let games = crate::parse(&text).unwrap();
+        assert_eq!(200, games.len());
+        assert_eq!(200, games.last().unwrap().id);
+        let possible: Answer = crate::p1::solve(&games).unwrap();
+        assert!(Answer::from(0u32) < possible && possible < Answer::from(200u32 * 201 / 2));
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_common::number::Answer;
+use aoc_common::{Result, Solution};
+
+pub mod bag;
+pub mod common;
+pub mod generator;
+pub mod options;
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod properties;
+pub mod stats;
+
+pub const INPUT: &str = include_str!("input/input.txt");
+pub const ANSWERS: &str = include_str!("../answers.toml");
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<common::Game>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(&self, input_text: &str) -> Result<Vec<common::Game>> {
+        parse(input_text)
+    }
+
+    fn part1(&self, games: &Vec<common::Game>) -> Result<Answer> {
+        p1::solve(games)
+    }
+
+    fn part2(&self, games: &Vec<common::Game>) -> Result<Answer> {
+        p2::solve(games)
+    }
+}
+
+pub fn parse(input_text: &str) -> Result<Vec<common::Game>> {
+    parse_with(input_text, &bag::Bag::default())
+}
+
+/// Parses the games of a variant of the puzzle, where the cubes can have any colour of `bag`
+pub fn parse_with(input_text: &str, bag: &bag::Bag) -> Result<Vec<common::Game>> {
+    common::games_from_string(input_text, bag)
+}
@@ -0,0 +1,89 @@
+use crate::bag::Bag;
+use aoc_common::{Error, Result};
+
+/// Command line options of the day 2 binaries
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Input path, `-` for stdin, or `None` for the bundled input
+    pub path: Option<String>,
+    pub bag: Bag,
+    /// Print statistics about the games instead of the answer
+    pub stats: bool,
+    /// Read the input line by line instead of loading it all in memory
+    pub stream: bool,
+}
+
+impl Options {
+    /// Reads `--bag PATH`, `--limit COLOUR=COUNT`, `--stats`, `--stream` and the optional input
+    /// path.
+    /// `--bag` replaces the whole bag, `--limit` changes or adds a single colour; they are applied
+    /// in order.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut args = args.into_iter();
+        let mut options = Options::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| Error::Invalid(format!("missing value for {}", arg)))
+            };
+            match arg.as_str() {
+                "--bag" => options.bag = Bag::load(&value()?)?,
+                "--limit" => {
+                    let limit: String = value()?;
+                    let (colour, count) = limit
+                        .split_once('=')
+                        .and_then(|(colour, count)| Some((colour, count.parse::<u32>().ok()?)))
+                        .ok_or_else(|| {
+                            Error::Invalid(format!("expected COLOUR=COUNT, got {:?}", limit))
+                        })?;
+                    options.bag.set(colour, count)?;
+                }
+                "--stats" => options.stats = true,
+                "--stream" => options.stream = true,
+                _ if arg.starts_with("--") => {
+                    return Err(Error::Invalid(format!("unknown option {}", arg)))
+                }
+                _ if options.path.is_none() => options.path = Some(arg),
+                _ => return Err(Error::Invalid(format!("unexpected argument {}", arg))),
+            }
+        }
+
+        if options.stream && options.stats {
+            return Err(Error::Invalid(
+                "--stream can't be combined with --stats".to_string(),
+            ));
+        }
+
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Options> {
+        Options::from_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_from_args() {
+        let options = args(&["--limit", "red=2", "input.txt", "--limit", "purple=3"]).unwrap();
+        assert_eq!(Some("input.txt".to_string()), options.path);
+        assert!(!options.stats);
+        let mut expected = Bag::default();
+        expected.set("red", 2).unwrap();
+        expected.set("purple", 3).unwrap();
+        assert_eq!(expected, options.bag);
+
+        assert_eq!(Options::default(), args(&[]).unwrap());
+        assert!(args(&["--stats"]).unwrap().stats);
+        assert!(args(&["--stream"]).unwrap().stream);
+        assert!(args(&["--stream", "--stats"]).is_err());
+        assert!(args(&["--limit", "red"]).is_err());
+        assert!(args(&["--limit"]).is_err());
+        assert!(args(&["--colour", "red"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::bag::Bag;
+use crate::common;
+use aoc_common::number::{self, Answer, Checked};
+use aoc_common::{input, Result};
+use std::io::BufRead;
+
+trait IsValid {
+    fn is_valid(&self, bag: &Bag) -> bool;
+}
+
+impl IsValid for common::Game {
+    fn is_valid(&self, bag: &Bag) -> bool {
+        self.draws.iter().all(|d| bag.allows(d))
+    }
+}
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    run_with(input_text, &Bag::default())
+}
+
+pub fn run_with(input_text: &str, bag: &Bag) -> Result<Answer> {
+    solve_with(&crate::parse_with(input_text, bag)?, bag)
+}
+
+/// Solves the input line by line as it is read, without loading it all in memory
+pub fn run_reader(reader: impl BufRead, bag: &Bag) -> Result<Answer> {
+    let mut total_sum: Answer = Answer::from(0u32);
+    input::for_each_line(reader, |line_index, line| {
+        let game: common::Game = common::game_from_string(line, line_index, bag)?;
+        if game.is_valid(bag) {
+            total_sum = std::mem::take(&mut total_sum).try_add(Answer::from(game.id))?;
+        }
+        Ok(())
+    })?;
+    Ok(total_sum)
+}
+
+pub fn solve(games: &[common::Game]) -> Result<Answer> {
+    solve_with(games, &Bag::default())
+}
+
+/// Sums the IDs of the games that could have been played with `bag`
+pub fn solve_with(games: &[common::Game], bag: &Bag) -> Result<Answer> {
+    number::sum(
+        games
+            .iter()
+            .filter(|game| game.is_valid(bag))
+            .map(|game| Answer::from(game.id)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(Answer::from(8u32), run(test_text).unwrap());
+    }
+
+    #[test]
+    fn test_run_reader() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(
+            Answer::from(8u32),
+            run_reader(test_text.as_bytes(), &Bag::default()).unwrap()
+        );
+        assert!(run_reader("Game 1: 1 purple".as_bytes(), &Bag::default()).is_err());
+    }
+
+    #[test]
+    fn test_run_with_bag() {
+        let test_text: &str = include_str!("input/test.txt");
+        let mut bag = Bag::default();
+        bag.set("red", 20).unwrap();
+        assert_eq!(Answer::from(11u32), run_with(test_text, &bag).unwrap());
+        bag.set("blue", 5).unwrap();
+        assert_eq!(Answer::from(7u32), run_with(test_text, &bag).unwrap());
+    }
+}
@@ -0,0 +1,74 @@
+use crate::bag::Bag;
+use crate::common;
+use aoc_common::number::{Answer, Checked};
+use aoc_common::{input, Result};
+use std::io::BufRead;
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    run_with(input_text, &Bag::default())
+}
+
+pub fn run_with(input_text: &str, bag: &Bag) -> Result<Answer> {
+    solve_with(&crate::parse_with(input_text, bag)?, bag)
+}
+
+/// Solves the input line by line as it is read, without loading it all in memory
+pub fn run_reader(reader: impl BufRead, bag: &Bag) -> Result<Answer> {
+    let mut total_sum: Answer = Answer::from(0u32);
+    input::for_each_line(reader, |line_index, line| {
+        let power: Answer = common::game_from_string(line, line_index, bag)?.power(bag)?;
+        total_sum = std::mem::take(&mut total_sum).try_add(power)?;
+        Ok(())
+    })?;
+    Ok(total_sum)
+}
+
+pub fn solve(games: &[common::Game]) -> Result<Answer> {
+    solve_with(games, &Bag::default())
+}
+
+/// Sums the powers of the games, multiplying the cubes needed of every colour of `bag`
+pub fn solve_with(games: &[common::Game], bag: &Bag) -> Result<Answer> {
+    games
+        .iter()
+        .map(|g| g.power(bag))
+        .try_fold(Answer::from(0u32), |total, power| total.try_add(power?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(Answer::from(2286u32), run(test_text).unwrap());
+    }
+
+    #[test]
+    fn test_run_reader() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(
+            Answer::from(2286u32),
+            run_reader(test_text.as_bytes(), &Bag::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_run_with_extra_colour() {
+        let mut bag = Bag::default();
+        bag.set("purple", 1).unwrap();
+        let test_text = "Game 1: 2 red, 3 green, 4 blue; 5 purple\nGame 2: 1 red, 1 green, 1 blue";
+        assert_eq!(Answer::from(120u32), run_with(test_text, &bag).unwrap());
+    }
+
+    #[test]
+    fn test_run_overflow() {
+        // The power is (2^32 - 1)^3, which only fits with the bigint feature
+        let test_text = "Game 1: 4294967295 red, 4294967295 green, 4294967295 blue";
+        match run(test_text) {
+            Ok(power) => assert_eq!("79228162458924105385300197375", power.to_string()),
+            Err(e) => assert!(matches!(e, aoc_common::Error::Overflow("u64"))),
+        }
+    }
+}
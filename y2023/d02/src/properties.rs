@@ -0,0 +1,99 @@
+//! Checks the solutions against naive reference implementations on random records
+
+use crate::bag::Bag;
+use crate::{p1, p2};
+use aoc_common::number::Answer;
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// Parses a record with plain `split`s, trusting it to be well formed. Each game is its ID and
+/// the most cubes of each colour shown in any draw.
+fn reference_games(input_text: &str) -> Vec<(u32, HashMap<&str, u32>)> {
+    input_text
+        .lines()
+        .map(|line| {
+            let (header, draws) = line.split_once(": ").unwrap();
+            let id: u32 = header.trim_start_matches("Game ").parse().unwrap();
+            let mut most: HashMap<&str, u32> = HashMap::new();
+            for cubes in draws.split("; ").flat_map(|draw| draw.split(", ")) {
+                let (count, colour) = cubes.split_once(' ').unwrap();
+                let count: u32 = count.parse().unwrap();
+                let entry = most.entry(colour).or_insert(0);
+                *entry = (*entry).max(count);
+            }
+            (id, most)
+        })
+        .collect()
+}
+
+fn reference_p1(input_text: &str) -> u32 {
+    let limits: HashMap<&str, u32> = HashMap::from([("red", 12), ("green", 13), ("blue", 14)]);
+    reference_games(input_text)
+        .into_iter()
+        .filter(|(_, most)| most.iter().all(|(colour, count)| count <= &limits[colour]))
+        .map(|(id, _)| id)
+        .sum()
+}
+
+fn reference_p2(input_text: &str) -> u32 {
+    reference_games(input_text)
+        .into_iter()
+        .map(|(_, most)| {
+            COLOURS
+                .iter()
+                .map(|colour| most.get(colour).copied().unwrap_or(0))
+                .product::<u32>()
+        })
+        .sum()
+}
+
+/// A draw shows between one and three distinct colours, in any order
+fn draw() -> impl Strategy<Value = String> {
+    prop::sample::subsequence(COLOURS.to_vec(), 1..=3)
+        .prop_shuffle()
+        .prop_flat_map(|colours| {
+            let counts = prop::collection::vec(1..=20u32, colours.len());
+            (Just(colours), counts)
+        })
+        .prop_map(|(colours, counts)| {
+            colours
+                .iter()
+                .zip(counts)
+                .map(|(colour, count)| format!("{count} {colour}"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        })
+}
+
+fn record() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::collection::vec(draw(), 1..6), 1..20).prop_map(|games| {
+        games
+            .iter()
+            .enumerate()
+            .map(|(index, draws)| format!("Game {}: {}", index + 1, draws.join("; ")))
+            .collect::<Vec<String>>()
+            .join("\n")
+    })
+}
+
+proptest! {
+    #[test]
+    fn p1_agrees_with_reference(input_text in record()) {
+        prop_assert_eq!(p1::run(&input_text).unwrap(), Answer::from(reference_p1(&input_text)));
+        prop_assert_eq!(
+            p1::run_reader(input_text.as_bytes(), &Bag::default()).unwrap(),
+            Answer::from(reference_p1(&input_text))
+        );
+    }
+
+    #[test]
+    fn p2_agrees_with_reference(input_text in record()) {
+        prop_assert_eq!(p2::run(&input_text).unwrap(), Answer::from(reference_p2(&input_text)));
+        prop_assert_eq!(
+            p2::run_reader(input_text.as_bytes(), &Bag::default()).unwrap(),
+            Answer::from(reference_p2(&input_text))
+        );
+    }
+}
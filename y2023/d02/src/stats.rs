@@ -0,0 +1,167 @@
+use crate::bag::Bag;
+use crate::common::Game;
+use aoc_common::number::{self, Answer, Checked};
+use aoc_common::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Longest bar of the powers histogram
+const BAR_WIDTH: usize = 40;
+
+/// Aggregate statistics over all the games of an input
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub games: usize,
+    /// Number of games that could have been played with the bag
+    pub possible: usize,
+    /// ID, minimum bag and number of cubes of the game needing the most cubes (the first one on
+    /// ties)
+    pub largest: Option<(u32, Bag, Answer)>,
+    pub total_power: Answer,
+    /// Number of games by power bucket. A bucket is keyed by its lowest power: 0, then powers of 2
+    /// each covering `[2^k, 2^(k+1))`.
+    pub powers: BTreeMap<Answer, usize>,
+}
+
+impl Stats {
+    /// Fails if the power or the number of cubes of a game, or the sum of all powers, doesn't fit in
+    /// an [`Answer`]
+    pub fn compute(games: &[Game], bag: &Bag) -> Result<Self> {
+        let mut stats = Stats {
+            games: games.len(),
+            possible: 0,
+            largest: None,
+            total_power: Answer::from(0u32),
+            powers: BTreeMap::new(),
+        };
+
+        for game in games {
+            let minimum: Bag = game.minimum_bag();
+            if bag.holds(&minimum) {
+                stats.possible += 1;
+            }
+            let power: Answer = minimum.power(bag)?;
+            *stats.powers.entry(bucket(&power)).or_default() += 1;
+            stats.total_power = std::mem::take(&mut stats.total_power).try_add(power)?;
+            let total: Answer = minimum.total()?;
+            if stats
+                .largest
+                .as_ref()
+                .is_none_or(|(_, _, largest_total)| total > *largest_total)
+            {
+                stats.largest = Some((game.id, minimum, total));
+            }
+        }
+
+        Ok(stats)
+    }
+}
+
+/// The lowest power of the histogram bucket containing `power`
+fn bucket(power: &Answer) -> Answer {
+    match number::bits(power) {
+        0 => Answer::from(0u8),
+        bits => Answer::from(1u8) << (bits - 1),
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Games: {} ({} possible with the bag)",
+            self.games, self.possible
+        )?;
+        if let Some((id, bag, total)) = &self.largest {
+            writeln!(
+                f,
+                "Largest bag: game {} needs {} ({} cubes)",
+                id, bag, total
+            )?;
+        }
+        writeln!(f, "Sum of powers: {}", self.total_power)?;
+        writeln!(f, "Powers:")?;
+        let max_count: usize = self.powers.values().copied().max().unwrap_or(0);
+        for (start, &count) in &self.powers {
+            // The bucket of 0 only holds 0, the others end right before twice their start
+            let end: Answer = match number::bits(start) {
+                0 => start.to_owned(),
+                _ => start + (start - Answer::from(1u8)),
+            };
+            let bar: usize = (count * BAR_WIDTH).div_ceil(max_count);
+            writeln!(
+                f,
+                "{:>10} ..{:>10} | {:>4} {}",
+                start,
+                end,
+                count,
+                "#".repeat(bar)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute() {
+        let test_text: &str = include_str!("input/test.txt");
+        let stats = Stats::compute(&crate::parse(test_text).unwrap(), &Bag::default()).unwrap();
+        assert_eq!(5, stats.games);
+        assert_eq!(3, stats.possible);
+        assert_eq!(Answer::from(2286u32), stats.total_power);
+        let (id, largest, total) = stats.largest.unwrap();
+        assert_eq!(3, id);
+        assert_eq!("6 blue, 13 green, 20 red", largest.to_string());
+        assert_eq!(Answer::from(39u32), total);
+        // Powers are 48, 12, 1560, 630 and 36
+        assert_eq!(
+            BTreeMap::from([
+                (Answer::from(8u32), 1),
+                (Answer::from(32u32), 2),
+                (Answer::from(512u32), 1),
+                (Answer::from(1024u32), 1),
+            ]),
+            stats.powers
+        );
+    }
+
+    #[test]
+    fn test_compute_large_bag() {
+        // The bag needs more cubes than a u32 holds
+        let games = crate::parse("Game 1: 4294967295 red, 1 blue").unwrap();
+        let stats = Stats::compute(&games, &Bag::default()).unwrap();
+        assert!(stats
+            .to_string()
+            .contains("game 1 needs 1 blue, 4294967295 red (4294967296 cubes)"));
+        assert_eq!(Answer::from(4_294_967_296u64), stats.largest.unwrap().2);
+    }
+
+    #[test]
+    fn test_compute_past_u64() {
+        // The power of the game only fits with the bigint feature, as for part 2
+        let games =
+            crate::parse("Game 1: 4294967295 red, 4294967295 green, 4294967295 blue").unwrap();
+        match Stats::compute(&games, &Bag::default()) {
+            Ok(stats) => assert_eq!(
+                "79228162458924105385300197375",
+                stats.total_power.to_string()
+            ),
+            Err(e) => assert!(matches!(e, aoc_common::Error::Overflow("u64"))),
+        }
+    }
+
+    #[test]
+    fn test_bucket() {
+        let bucket = |power: u64| bucket(&Answer::from(power));
+        assert_eq!(Answer::from(0u32), bucket(0));
+        assert_eq!(Answer::from(1u32), bucket(1));
+        assert_eq!(Answer::from(4u32), bucket(7));
+        assert_eq!(Answer::from(8u32), bucket(8));
+        assert_eq!(Answer::from(1u64 << 31), bucket(u32::MAX.into()));
+        assert_eq!(Answer::from(1u64 << 63), bucket(u64::MAX));
+    }
+}
@@ -0,0 +1,42 @@
+//! Compares compiling a regex on every call with the cached `regex!` of [`p1::is_symbol`], the
+//! per-character symbol check of part 1. Run it with `cargo bench -p y2023-d03`.
+
+use aoc_common::bench::{measure, BenchConfig, Stats};
+use aoc_grid::Grid;
+use regex::Regex;
+use y2023_d03::p1;
+
+/// A schematic line of 2023 day 3
+const LINE: &str =
+    "...733.......289..62..........*...............939.....280....#.....................105...$";
+
+/// [`p1::is_symbol`] before the regex was cached
+fn is_symbol_uncached(ch: char) -> bool {
+    Regex::new(r"[^\d.\s]").unwrap().is_match(&ch.to_string())
+}
+
+fn report(name: &str, stats: Stats) {
+    println!(
+        "{:<10} {:>10.2?} {:>10.2?} {:>10.2?}",
+        name, stats.min, stats.median, stats.p95
+    );
+}
+
+fn main() {
+    let config = BenchConfig::default();
+    let count_symbols =
+        |is_symbol: fn(char) -> bool| LINE.chars().filter(|&c| is_symbol(c)).count();
+
+    println!("Cost of checking one line ({} characters)", LINE.len());
+    println!("{:<10} {:>10} {:>10} {:>10}", "", "Min", "Median", "P95");
+    report(
+        "uncached",
+        measure(config, || count_symbols(is_symbol_uncached)),
+    );
+    report("cached", measure(config, || count_symbols(p1::is_symbol)));
+
+    let all_chars: Grid<char> = y2023_d03::parse(y2023_d03::INPUT).unwrap();
+    println!();
+    println!("Cost of part 1 on the bundled input");
+    report("cached", measure(config, || p1::solve(&all_chars)));
+}
@@ -0,0 +1,57 @@
+use aoc_common::rng::Rng;
+use std::io::{self, Write};
+
+/// Width of the generated schematics, the same as the real inputs
+const WIDTH: usize = 140;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+/// Generates an engine schematic of `lines` rows: part numbers of 1 to 3 digits (without leading
+/// zeros), symbols (a bit over half of them `*`, so that some of them are gears) and `.`
+/// everywhere else
+pub fn generate(rng: &mut Rng, lines: usize, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..lines {
+        let mut row: Vec<u8> = Vec::with_capacity(WIDTH);
+        while row.len() < WIDTH {
+            let room: usize = WIDTH - row.len();
+            let after_digit: bool = row.last().is_some_and(u8::is_ascii_digit);
+            match rng.range(0..100) {
+                // Numbers must be separated, or they would be read as a single one
+                0..12 if !after_digit => {
+                    let len: u64 = rng.range(1..4).min(room as u64);
+                    row.push(b'1' + rng.range(0..9) as u8);
+                    row.extend((1..len).map(|_| b'0' + rng.range(0..10) as u8));
+                }
+                12..16 => row.push(b'*'),
+                16..20 => row.push(*rng.choose(SYMBOLS)),
+                _ => row.push(b'.'),
+            }
+        }
+        row.push(b'\n');
+        out.write_all(&row)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::number::Answer;
+
+    fn generated(seed: u64, lines: usize) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        generate(&mut Rng::new(seed), lines, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_generate() {
+        let text: String = generated(1, 50);
+        assert_eq!(text, generated(1, 50));
+        assert_ne!(text, generated(2, 50));
+        let grid = crate::parse(&text).unwrap();
+        assert_eq!((WIDTH, 50), (grid.width(), grid.height()));
+        assert!(crate::p1::solve(&grid).unwrap() > Answer::from(0u32));
+        assert!(crate::p2::solve(&grid).unwrap() > Answer::from(0u32));
+    }
+}
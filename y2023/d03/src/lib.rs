@@ -0,0 +1,38 @@
+use aoc_common::number::Answer;
+use aoc_common::{Result, Solution};
+use aoc_grid::Grid;
+
+pub mod generator;
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod properties;
+
+pub const INPUT: &str = include_str!("input/input.txt");
+pub const ANSWERS: &str = include_str!("../answers.toml");
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Grid<char>;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(&self, input_text: &str) -> Result<Grid<char>> {
+        parse(input_text)
+    }
+
+    fn part1(&self, all_chars: &Grid<char>) -> Result<Answer> {
+        p1::solve(all_chars)
+    }
+
+    fn part2(&self, all_chars: &Grid<char>) -> Result<Answer> {
+        p2::solve(all_chars)
+    }
+}
+
+/// Lines of the schematic do not need to have the same length: missing cells at the end of the
+/// shorter lines are treated as empty ('.')
+pub fn parse(input_text: &str) -> Result<Grid<char>> {
+    Ok(Grid::parse_padded(input_text, '.'))
+}
@@ -0,0 +1,380 @@
+use aoc_common::number::{Answer, Checked};
+use aoc_common::{regex, Result};
+use aoc_grid::{Grid, Position};
+use itertools::Itertools;
+use std::fmt;
+
+#[derive(Debug)]
+struct Number {
+    digits: Vec<u32>,
+    is_part_num: bool,
+}
+
+trait NumberTrait {
+    fn add_digit(&mut self, d: u32);
+    fn set_as_part_num(&mut self);
+    fn digits_to_int(&self) -> Result<Answer>;
+}
+
+impl NumberTrait for Number {
+    fn add_digit(&mut self, d: u32) {
+        self.digits.push(d);
+    }
+
+    fn set_as_part_num(&mut self) {
+        self.is_part_num = true;
+    }
+
+    /// Fails instead of overflowing on long runs of digits
+    fn digits_to_int(&self) -> Result<Answer> {
+        self.digits
+            .iter()
+            .try_fold(Answer::from(0u32), |result, &digit| {
+                result
+                    .try_mul(Answer::from(10u32))?
+                    .try_add(Answer::from(digit))
+            })
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}|{}", self.digits.iter().join(""), self.is_part_num)
+    }
+}
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    solve(&crate::parse(input_text)?)
+}
+
+pub fn solve(all_chars: &Grid<char>) -> Result<Answer> {
+    let all_numbers = get_all_numbers(all_chars);
+    // println!("{:?}", all_numbers);
+    all_numbers
+        .iter()
+        .filter(|n| n.is_part_num)
+        .try_fold(Answer::from(0u32), |total, n| {
+            total.try_add(n.digits_to_int()?)
+        })
+}
+
+/// The value of every number of the schematic, in reading order
+#[cfg(test)]
+pub(crate) fn number_values(all_chars: &Grid<char>) -> Result<Vec<Answer>> {
+    get_all_numbers(all_chars)
+        .iter()
+        .map(|n| n.digits_to_int())
+        .collect()
+}
+
+fn get_all_numbers(all_chars: &Grid<char>) -> Vec<Number> {
+    let mut all_numbers: Vec<Number> = Vec::new();
+    for (row_index, row) in all_chars.rows().enumerate() {
+        let mut is_prev_char_a_digit = false;
+        for (col_index, ch) in row.iter().enumerate() {
+            if *ch == '.' {
+                is_prev_char_a_digit = false;
+                continue;
+            }
+            match ch.to_digit(10) {
+                Some(num) => {
+                    let curr_position = Position {
+                        row: row_index,
+                        col: col_index,
+                    };
+                    if is_prev_char_a_digit {
+                        let last_num = all_numbers.last_mut().unwrap();
+                        last_num.add_digit(num);
+                        // If we have already found previously that the last Number was a part number,
+                        // we don't have to make that calculation again
+                        if !last_num.is_part_num
+                            && is_adjacent_to_symbol_num(&curr_position, all_chars)
+                        {
+                            last_num.set_as_part_num();
+                        }
+                    } else {
+                        let is_part_num = is_adjacent_to_symbol_num(&curr_position, all_chars);
+                        all_numbers.push(Number {
+                            digits: vec![num],
+                            is_part_num,
+                        })
+                    }
+                    is_prev_char_a_digit = true;
+                }
+                None => {
+                    is_prev_char_a_digit = false;
+                }
+            }
+        }
+    }
+    all_numbers
+}
+
+fn is_adjacent_to_symbol_num(position: &Position, all_chars: &Grid<char>) -> bool {
+    all_chars
+        .neighbours8(*position)
+        .any(|neighbour| is_symbol(all_chars[neighbour]))
+}
+
+/// Whether a cell of the schematic holds a symbol: anything but a digit, a `.` or whitespace
+pub fn is_symbol(ch: char) -> bool {
+    regex!(r"[^\d.\s]").is_match(ch.encode_utf8(&mut [0; 4]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_digits_to_int_works() {
+        let num = Number {
+            digits: vec![0, 1, 0, 2, 3, 4, 0],
+            is_part_num: false,
+        };
+        assert_eq!(num.digits_to_int().unwrap(), Answer::from(102340u32));
+    }
+
+    #[test]
+    fn it_works_2x2() {
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.'],
+                vec!['.', '.']
+            ]))),
+            ""
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1', '.'],
+                vec!['.', '.']
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1', '.'],
+                vec!['3', '.']
+            ]))),
+            "1|false,3|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1', '.'],
+                vec!['.', '4']
+            ]))),
+            "1|false,4|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1', '2'],
+                vec!['3', '4']
+            ]))),
+            "12|false,34|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1', '2'],
+                vec!['#', '4']
+            ]))),
+            "12|true,4|true"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['!', '2'],
+                vec!['3', '4']
+            ]))),
+            "2|true,34|true"
+        );
+    }
+
+    #[test]
+    fn it_works_3x3() {
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+            ]))),
+            ""
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1', '.', '.'],
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '1', '.'],
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '1'],
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '.'],
+                vec!['1', '.', '.'],
+                vec!['.', '.', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '.'],
+                vec!['.', '1', '.'],
+                vec!['.', '.', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '.'],
+                vec!['.', '.', '1'],
+                vec!['.', '.', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+                vec!['1', '.', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+                vec!['.', '1', '.'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['.', '.', '.'],
+                vec!['.', '.', '.'],
+                vec!['.', '.', '1'],
+            ]))),
+            "1|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1', '2', '.'],
+                vec!['.', '.', '#'],
+                vec!['.', '.', '.'],
+            ]))),
+            "12|true"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['7', '.', '.'],
+                vec!['.', '8', '?'],
+                vec!['1', '2', '3'],
+            ]))),
+            "7|false,8|true,123|true"
+        );
+    }
+
+    #[test]
+    fn it_works_example() {
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['4', '6', '7', '.', '.', '1', '1', '4', '.', '.'],
+                vec!['.', '.', '.', '*', '.', '.', '.', '.', '.', '.'],
+                vec!['.', '.', '3', '5', '.', '.', '6', '3', '3', '.'],
+                vec!['.', '.', '.', '.', '.', '.', '#', '.', '.', '.'],
+                vec!['6', '1', '7', '*', '.', '.', '.', '.', '.', '.'],
+                vec!['.', '.', '.', '.', '.', '+', '.', '5', '8', '.'],
+                vec!['.', '.', '5', '9', '2', '.', '.', '.', '.', '.'],
+                vec!['.', '.', '.', '.', '.', '.', '7', '5', '5', '.'],
+                vec!['.', '.', '.', '$', '.', '*', '.', '.', '.', '.'],
+                vec!['.', '6', '6', '4', '.', '5', '9', '8', '.', '.'],
+            ]))),
+            "467|true,114|false,35|true,633|true,617|true,58|false,592|true,755|true,664|true,598|true"
+        );
+    }
+
+    #[test]
+    fn it_works_single_row() {
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![vec!['7']]))),
+            "7|false"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![vec![
+                '1', '2', '.', '3', '#', '.', '4'
+            ]]))),
+            "12|false,3|true,4|false"
+        );
+    }
+
+    #[test]
+    fn it_works_single_column() {
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&grid(vec![
+                vec!['1'],
+                vec!['.'],
+                vec!['2'],
+                vec!['%'],
+                vec!['3'],
+            ]))),
+            "1|false,2|true,3|true"
+        );
+    }
+
+    #[test]
+    fn it_works_ragged() {
+        // Rows shorter and longer than the first one
+        assert_eq!(
+            nums_to_string(&get_all_numbers(
+                &crate::parse("467\n...*\n..35\n1\n.....-9").unwrap()
+            )),
+            "467|true,35|true,1|false,9|true"
+        );
+        assert_eq!(
+            nums_to_string(&get_all_numbers(&crate::parse("1\n..#\n\n45").unwrap())),
+            "1|false,45|false"
+        );
+        assert_eq!(Answer::from(502u32), run("467\n...*\n..35\n1").unwrap());
+    }
+
+    #[test]
+    fn it_works_empty() {
+        assert_eq!(Answer::from(0u32), run("").unwrap());
+    }
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(Answer::from(4361u32), run(test_text).unwrap());
+    }
+
+    #[test]
+    fn test_run_overflow() {
+        // 25 digits only fit with the bigint feature
+        match run("1234567890123456789012345#") {
+            Ok(sum) => assert_eq!("1234567890123456789012345", sum.to_string()),
+            Err(e) => assert!(matches!(e, aoc_common::Error::Overflow("u64"))),
+        }
+    }
+
+    fn grid(rows: Vec<Vec<char>>) -> Grid<char> {
+        Grid::from_rows(rows).unwrap()
+    }
+
+    fn nums_to_string(all_numbers: &[Number]) -> String {
+        all_numbers.iter().map(|n| n.to_string()).join(",")
+    }
+}
@@ -0,0 +1,229 @@
+use aoc_common::number::{self, Answer, Checked};
+use aoc_common::Result;
+use aoc_grid::{Grid, Position};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Clone)]
+struct NumberDigit {
+    digit: u8,
+    position: Position,
+    adjacent_asterisk_positions: Vec<Position>,
+}
+
+impl NumberDigit {
+    pub fn new(position: Position, all_chars: &Grid<char>) -> Self {
+        let curr_char: char = all_chars[position];
+        let digit: u8 = Self::get_digit(curr_char);
+        let adjacent_asterisk_positions: Vec<Position> =
+            Self::get_adjacent_asterisk_positions(all_chars, &position);
+        NumberDigit {
+            digit,
+            position,
+            adjacent_asterisk_positions,
+        }
+    }
+
+    fn get_digit(char: char) -> u8 {
+        match char {
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            '3' => 3,
+            '4' => 4,
+            '5' => 5,
+            '6' => 6,
+            '7' => 7,
+            '8' => 8,
+            '9' => 9,
+            _ => panic!("You should have checked that there was a digit at this position! There was a {} instead!", char),
+        }
+    }
+
+    fn get_adjacent_asterisk_positions(
+        all_chars: &Grid<char>,
+        self_position: &Position,
+    ) -> Vec<Position> {
+        all_chars
+            .neighbours8(*self_position)
+            .filter(|&neighbour| all_chars[neighbour] == '*')
+            .collect::<Vec<Position>>()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Number {
+    digits: Vec<NumberDigit>,
+}
+
+impl Number {
+    pub fn new() -> Self {
+        Number { digits: vec![] }
+    }
+}
+
+trait NumberTrait {
+    fn add_digit(&mut self, position: Position, all_chars: &Grid<char>);
+    fn digits_to_int(&self) -> Result<Answer>;
+    fn get_all_adjacent_asterisk_positions(&self) -> Vec<Position>;
+}
+
+impl NumberTrait for Number {
+    fn add_digit(&mut self, position: Position, all_chars: &Grid<char>) {
+        self.digits.push(NumberDigit::new(position, all_chars));
+    }
+
+    /// Fails instead of overflowing on long runs of digits
+    fn digits_to_int(&self) -> Result<Answer> {
+        self.digits
+            .iter()
+            .try_fold(Answer::from(0u32), |result, digit| {
+                result
+                    .try_mul(Answer::from(10u32))?
+                    .try_add(Answer::from(digit.digit))
+            })
+    }
+
+    fn get_all_adjacent_asterisk_positions(&self) -> Vec<Position> {
+        let mut result: Vec<Position> = Vec::new();
+        for digit in self.digits.iter() {
+            result.extend(digit.adjacent_asterisk_positions.clone())
+        }
+        result
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.digits.eq(&other.digits)
+    }
+}
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    solve(&crate::parse(input_text)?)
+}
+
+pub fn solve(all_chars: &Grid<char>) -> Result<Answer> {
+    // Get all numbers, and for each of them, save the position of all surrounding '*'
+    let all_numbers = get_all_numbers(all_chars);
+
+    // Now we have to reverse the assignment: for each asterisk, we need to find the numbers that
+    // are surrounding it
+    let mut asterisks_to_numbers: HashMap<Position, Vec<Number>> = HashMap::new();
+    for number in all_numbers {
+        for asterisk_position in number.get_all_adjacent_asterisk_positions() {
+            let numbers: &mut Vec<Number> =
+                asterisks_to_numbers.entry(asterisk_position).or_default();
+            if !numbers.contains(&number) {
+                numbers.push(number.clone());
+            }
+        }
+    }
+
+    let result: Answer = asterisks_to_numbers
+        // We don't care about asterisk positions here so we take the values only
+        // https://stackoverflow.com/questions/56724014/how-do-i-collect-the-values-of-a-hashmap-into-a-vector#comment131100540_63727456
+        .into_values()
+        // Only consider the cases where an asterisk had 2 adjacent numbers (those asterisks were "gears")
+        .filter(|numbers: &Vec<Number>| numbers.len() == 2)
+        // We get a Vec<Vec<Number>> where each inner Vec has exactly 2 Numbers
+        .map(|numbers| {
+            numbers
+                .iter()
+                // Map each Number to an Answer, which fails if it doesn't fit
+                .map(|n| n.digits_to_int())
+                // Here we have an array of 2 Answers, which are the 2 numbers which are adjacent to one gear
+                .collect::<Result<Vec<Answer>>>()
+                // We multiply these 2 Answers, which may overflow too
+                .and_then(number::product)
+        })
+        // Here we have an array of Answers. Each element of this array is the multiplication of
+        // the 2 part numbers that were adjacent to a gear.
+        .collect::<Result<Vec<Answer>>>()?
+        .into_iter()
+        // And finally, we sum all these quantities
+        .try_fold(Answer::from(0u32), Checked::try_add)?;
+
+    Ok(result)
+}
+
+/// The value of every number of the schematic, in reading order
+#[cfg(test)]
+pub(crate) fn number_values(all_chars: &Grid<char>) -> Result<Vec<Answer>> {
+    get_all_numbers(all_chars)
+        .iter()
+        .map(|n| n.digits_to_int())
+        .collect()
+}
+
+fn get_all_numbers(all_chars: &Grid<char>) -> Vec<Number> {
+    let mut all_numbers: Vec<Number> = Vec::new();
+    for (row_index, row) in all_chars.rows().enumerate() {
+        let mut is_prev_char_a_digit = false;
+        for (col_index, ch) in row.iter().enumerate() {
+            match ch.to_digit(10) {
+                Some(_) => {
+                    let curr_position = Position {
+                        row: row_index,
+                        col: col_index,
+                    };
+                    if is_prev_char_a_digit {
+                        let last_num = all_numbers.last_mut().unwrap();
+                        last_num.add_digit(curr_position, all_chars);
+                    } else {
+                        let mut new_number = Number::new();
+                        new_number.add_digit(curr_position, all_chars);
+                        all_numbers.push(new_number)
+                    }
+                    is_prev_char_a_digit = true;
+                }
+                None => {
+                    is_prev_char_a_digit = false;
+                }
+            }
+        }
+    }
+    all_numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(Answer::from(467835u32), run(test_text).unwrap());
+    }
+
+    #[test]
+    fn test_run_overflow() {
+        // Both numbers fit in a u64, but their product only fits with the bigint feature
+        match run("9999999999*9999999999") {
+            Ok(sum) => assert_eq!("99999999980000000001", sum.to_string()),
+            Err(e) => assert!(matches!(e, aoc_common::Error::Overflow("u64"))),
+        }
+    }
+
+    #[test]
+    fn test_run_single_row() {
+        assert_eq!(Answer::from(408u32), run("12*34").unwrap());
+        assert_eq!(Answer::from(408u32), run("12*34*").unwrap());
+        assert_eq!(Answer::from(0u32), run("*12*").unwrap());
+    }
+
+    #[test]
+    fn test_run_single_column() {
+        assert_eq!(Answer::from(6u32), run("3\n*\n2").unwrap());
+        assert_eq!(Answer::from(0u32), run("3\n*\n.\n2").unwrap());
+    }
+
+    #[test]
+    fn test_run_ragged() {
+        assert_eq!(Answer::from(16345u32), run("467\n...*\n..35").unwrap());
+        assert_eq!(
+            Answer::from(16345u32),
+            run("467..\n...*\n..35....").unwrap()
+        );
+        assert_eq!(Answer::from(0u32), run("").unwrap());
+    }
+}
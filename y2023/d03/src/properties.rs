@@ -0,0 +1,123 @@
+//! Checks the solutions against naive reference implementations on random schematics
+
+use crate::{p1, p2};
+use aoc_common::number::Answer;
+use proptest::prelude::*;
+
+/// A number of the schematic: its row, the columns it spans and its value
+struct Run {
+    row: usize,
+    cols: std::ops::Range<usize>,
+    value: u32,
+}
+
+impl Run {
+    fn touches(&self, row: usize, col: usize) -> bool {
+        self.row.abs_diff(row) <= 1 && self.cols.start <= col + 1 && col <= self.cols.end
+    }
+}
+
+/// Finds the numbers by splitting every line on whatever isn't a digit
+fn runs(input_text: &str) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    for (row, line) in input_text.lines().enumerate() {
+        let mut start: usize = 0;
+        for piece in line.split(|ch: char| !ch.is_ascii_digit()) {
+            if !piece.is_empty() {
+                runs.push(Run {
+                    row,
+                    cols: start..start + piece.len(),
+                    value: piece.parse().unwrap(),
+                });
+            }
+            start += piece.len() + 1;
+        }
+    }
+    runs
+}
+
+/// Every cell of the schematic holding `wanted`
+fn cells(input_text: &str, wanted: impl Fn(char) -> bool) -> Vec<(usize, usize)> {
+    input_text
+        .lines()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, ch)| wanted(ch))
+                .map(move |(col, _)| (row, col))
+        })
+        .collect()
+}
+
+fn reference_p1(input_text: &str) -> u32 {
+    let symbols = cells(input_text, |ch| !ch.is_ascii_digit() && ch != '.');
+    runs(input_text)
+        .iter()
+        .filter(|run| symbols.iter().any(|&(row, col)| run.touches(row, col)))
+        .map(|run| run.value)
+        .sum()
+}
+
+fn reference_p2(input_text: &str) -> u32 {
+    let numbers: Vec<Run> = runs(input_text);
+    cells(input_text, |ch| ch == '*')
+        .into_iter()
+        .map(|(row, col)| {
+            let adjacent: Vec<u32> = numbers
+                .iter()
+                .filter(|run| run.touches(row, col))
+                .map(|run| run.value)
+                .collect();
+            if adjacent.len() == 2 {
+                adjacent[0] * adjacent[1]
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Lines of numbers (kept short so that sums and gear ratios fit in a `u32`), symbols and empty
+/// cells. The lines may have different lengths.
+fn schematic() -> impl Strategy<Value = String> {
+    let token = prop_oneof![
+        3 => Just(".".to_string()),
+        2 => (0..1000u32).prop_map(|value| value.to_string()),
+        1 => prop::sample::select(vec!["*", "#", "+", "$", "/"]).prop_map(String::from),
+    ];
+    let line = prop::collection::vec(token, 1..12).prop_map(|tokens| {
+        let mut line = String::new();
+        for token in tokens {
+            // Two numbers in a row would be read as a single, longer one
+            if line.ends_with(|ch: char| ch.is_ascii_digit())
+                && token.starts_with(|ch: char| ch.is_ascii_digit())
+            {
+                line.push('.');
+            }
+            line.push_str(&token);
+        }
+        line
+    });
+    prop::collection::vec(line, 1..10).prop_map(|lines| lines.join("\n"))
+}
+
+proptest! {
+    #[test]
+    fn p1_agrees_with_reference(input_text in schematic()) {
+        prop_assert_eq!(p1::run(&input_text).unwrap(), Answer::from(reference_p1(&input_text)));
+    }
+
+    #[test]
+    fn p2_agrees_with_reference(input_text in schematic()) {
+        prop_assert_eq!(p2::run(&input_text).unwrap(), Answer::from(reference_p2(&input_text)));
+    }
+
+    #[test]
+    fn both_parts_find_the_same_numbers(input_text in schematic()) {
+        let all_chars = crate::parse(&input_text).unwrap();
+        let expected: Vec<Answer> = runs(&input_text).iter().map(|run| Answer::from(run.value)).collect();
+        prop_assert_eq!(p1::number_values(&all_chars).unwrap(), expected.clone());
+        prop_assert_eq!(p2::number_values(&all_chars).unwrap(), expected);
+    }
+}
@@ -0,0 +1,45 @@
+//! Compares the merge of the sorted lists with the former per-ID scan of the second list, on
+//! generated inputs of growing size. Run it with `cargo bench -p y2024-d01`.
+
+use aoc_common::bench::{measure, BenchConfig, Stats};
+use aoc_common::rng::Rng;
+use y2024_d01::{generator, p2, Lists};
+
+/// The quadratic version is only measured up to this many lines, as it takes minutes beyond
+const QUADRATIC_MAX_LINES: usize = 20_000;
+
+fn lists(lines: usize) -> Lists {
+    let mut text: Vec<u8> = Vec::new();
+    generator::generate(&mut Rng::new(1), lines, &mut text).unwrap();
+    y2024_d01::parse_reader(text.as_slice()).unwrap()
+}
+
+fn report(name: &str, lines: usize, stats: Stats) {
+    println!(
+        "{:<10} {:>10} {:>10.2?} {:>10.2?} {:>10.2?}",
+        name, lines, stats.min, stats.median, stats.p95
+    );
+}
+
+fn main() {
+    let config = BenchConfig {
+        warmup: 1,
+        samples: 10,
+    };
+
+    println!(
+        "{:<10} {:>10} {:>10} {:>10} {:>10}",
+        "", "Lines", "Min", "Median", "P95"
+    );
+    for lines in [1_000, 20_000, 1_000_000, 5_000_000] {
+        let lists: Lists = lists(lines);
+        if lines <= QUADRATIC_MAX_LINES {
+            report(
+                "quadratic",
+                lines,
+                measure(config, || p2::solve_quadratic(&lists)),
+            );
+        }
+        report("merge", lines, measure(config, || p2::solve(&lists)));
+    }
+}
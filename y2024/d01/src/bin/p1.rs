@@ -1,61 +1,43 @@
-use regex::Regex;
-use std::io;
+use aoc_common::number::Answer;
+use aoc_common::{input, OrExit};
+use std::borrow::Cow;
+use std::env;
+use std::io::BufRead;
 use std::time::{Duration, Instant};
+use y2024_d01::metrics::report;
+use y2024_d01::options::Options;
+use y2024_d01::p1::{run, run_reader};
+use y2024_d01::INPUT;
 
 fn main() {
-    let input_text: &str = include_str!("input/input.txt");
-
-    let start: Instant = Instant::now();
-    let result: i32 = run(input_text).expect("Should have worked");
-    let duration: Duration = start.elapsed();
-
-    println!("Result: {}", result);
-    println!("Execution time: {:.2?}", duration);
-}
-
-fn run(input_text: &str) -> io::Result<i32> {
-    let first_num_regex = Regex::new(r#"^\d+"#).unwrap();
-    let last_num_regex = Regex::new(r#"\d+$"#).unwrap();
-    let mut all_first_nums: Vec<i32> = vec![];
-    let mut all_last_nums: Vec<i32> = vec![];
-
-    for line in input_text.lines() {
-        let first_num = first_num_regex
-            .find(line)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        let last_num = last_num_regex
-            .find(line)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        all_first_nums.push(first_num);
-        all_last_nums.push(last_num);
+    let Options {
+        path,
+        stream,
+        metrics,
+    } = Options::from_args(env::args().skip(1)).or_exit();
+
+    if !metrics.is_empty() {
+        print!("{}", report(path.as_deref(), stream, &metrics).or_exit());
+        return;
     }
 
-    all_first_nums.sort();
-    all_last_nums.sort();
-
-    assert_eq!(all_first_nums.len(), all_last_nums.len());
+    if stream {
+        let reader: Box<dyn BufRead> = input::open(path.as_deref(), INPUT).or_exit();
+        let start: Instant = Instant::now();
+        let result: Answer = run_reader(reader).or_exit();
+        let duration: Duration = start.elapsed();
 
-    let mut differences: i32 = 0;
-    for index in 0..all_first_nums.len() {
-        differences += (all_last_nums[index] - all_first_nums[index]).abs();
+        println!("Result: {}", result);
+        println!("Execution time: {:.2?}", duration);
+        return;
     }
 
-    Ok(differences)
-}
+    let input_text: Cow<str> = input::load(path.as_deref(), INPUT).or_exit();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    let start: Instant = Instant::now();
+    let result: Answer = run(&input_text).or_exit();
+    let duration: Duration = start.elapsed();
 
-    #[test]
-    fn test_run() {
-        let test_text: &str = include_str!("input/test.txt");
-        assert_eq!(11i32, run(test_text).unwrap());
-    }
+    println!("Result: {}", result);
+    println!("Execution time: {:.2?}", duration);
 }
@@ -0,0 +1,37 @@
+use aoc_common::rng::Rng;
+use std::io::{self, Write};
+
+/// Generates `lines` pairs of 5-digit location IDs. Half of the right IDs are copies of left
+/// ones, so that the similarity score is not 0.
+pub fn generate(rng: &mut Rng, lines: usize, out: &mut dyn Write) -> io::Result<()> {
+    let left: Vec<u64> = (0..lines).map(|_| rng.range(10000..100000)).collect();
+    for &left_id in &left {
+        let right_id: u64 = match rng.chance(50) {
+            true => *rng.choose(&left),
+            false => rng.range(10000..100000),
+        };
+        writeln!(out, "{}   {}", left_id, right_id)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::number::Answer;
+
+    fn generated(seed: u64, lines: usize) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        generate(&mut Rng::new(seed), lines, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_generate() {
+        let text: String = generated(1, 200);
+        assert_eq!(text, generated(1, 200));
+        assert_ne!(text, generated(2, 200));
+        assert_eq!(200, crate::parse(&text).unwrap().first_nums().len());
+        assert!(crate::p2::run(&text).unwrap() > Answer::from(0u32));
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_common::number::Answer;
+use aoc_common::{input, parse, Error, Result, Solution};
+use std::io::BufRead;
+
+pub mod generator;
+pub mod metrics;
+pub mod options;
+pub mod p1;
+pub mod p2;
+#[cfg(test)]
+mod properties;
+
+pub const INPUT: &str = include_str!("input/input.txt");
+pub const ANSWERS: &str = include_str!("../answers.toml");
+
+pub struct Day01;
+
+/// The two location ID lists, both sorted in ascending order. Only [`sorted`] makes them, so that
+/// the parts can't be given unsorted lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lists(Vec<u32>, Vec<u32>);
+
+impl Lists {
+    pub fn first_nums(&self) -> &[u32] {
+        &self.0
+    }
+
+    pub fn last_nums(&self) -> &[u32] {
+        &self.1
+    }
+}
+
+/// The two location ID lists in input order: the IDs at the same index were on the same line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pairs(pub Vec<u32>, pub Vec<u32>);
+
+impl Solution for Day01 {
+    type Parsed = Lists;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    fn parse(&self, input_text: &str) -> Result<Lists> {
+        parse(input_text)
+    }
+
+    fn part1(&self, lists: &Lists) -> Result<Answer> {
+        p1::solve(lists)
+    }
+
+    fn part2(&self, lists: &Lists) -> Result<Answer> {
+        p2::solve(lists)
+    }
+}
+
+pub fn parse(input_text: &str) -> Result<Lists> {
+    Ok(sorted(parse_pairs(input_text)?))
+}
+
+/// Parses the input line by line as it is read. Only the lists are kept in memory, not the text.
+pub fn parse_reader(reader: impl BufRead) -> Result<Lists> {
+    Ok(sorted(parse_pairs_reader(reader)?))
+}
+
+/// Parses both lists without sorting them, for metrics that depend on which IDs were paired
+pub fn parse_pairs(input_text: &str) -> Result<Pairs> {
+    let mut all_first_nums: Vec<u32> = vec![];
+    let mut all_last_nums: Vec<u32> = vec![];
+
+    for (line_index, line) in input_text.lines().enumerate() {
+        let (first_num, last_num) = parse_line(line_index, line)?;
+        all_first_nums.push(first_num);
+        all_last_nums.push(last_num);
+    }
+
+    Ok(Pairs(all_first_nums, all_last_nums))
+}
+
+/// Like [`parse_pairs`], reading the input line by line
+pub fn parse_pairs_reader(reader: impl BufRead) -> Result<Pairs> {
+    let mut all_first_nums: Vec<u32> = vec![];
+    let mut all_last_nums: Vec<u32> = vec![];
+
+    input::for_each_line(reader, |line_index, line| {
+        let (first_num, last_num) = parse_line(line_index, line)?;
+        all_first_nums.push(first_num);
+        all_last_nums.push(last_num);
+        Ok(())
+    })?;
+
+    Ok(Pairs(all_first_nums, all_last_nums))
+}
+
+/// Sorts both lists of `pairs` independently
+pub fn sorted(Pairs(mut all_first_nums, mut all_last_nums): Pairs) -> Lists {
+    all_first_nums.sort();
+    all_last_nums.sort();
+    Lists(all_first_nums, all_last_nums)
+}
+
+fn parse_line(line_index: usize, line: &str) -> Result<(u32, u32)> {
+    let mut line_fields = parse::fields(line);
+    let (Some(first), Some(last), None) =
+        (line_fields.next(), line_fields.next(), line_fields.next())
+    else {
+        return Err(Error::parse(line_index, 1, line, "expected two integers"));
+    };
+    Ok((
+        parse::field(line_index, first, "expected a location ID")?,
+        parse::field(line_index, last, "expected a location ID")?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "line 2: expected two integers (column 1: \"4\")",
+            parse("3   4\n4\n").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1: expected a location ID (column 5: \"x\")",
+            parse("3   x\n").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_reader() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(
+            parse(test_text).unwrap(),
+            parse_reader(test_text.as_bytes()).unwrap()
+        );
+        assert!(parse_reader("3   x\n".as_bytes()).is_err());
+    }
+}
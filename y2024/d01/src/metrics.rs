@@ -0,0 +1,370 @@
+//! Ways of comparing the two location ID lists, beyond the distance and similarity score of the
+//! puzzle
+
+use crate::{p1, p2, sorted, Lists, Pairs};
+use aoc_common::number::{Answer, Checked};
+use aoc_common::{input, Error, Result};
+use std::cmp::Ordering;
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of the differences between the sorted lists (part 1)
+    L1,
+    /// Euclidean distance between the sorted lists
+    L2,
+    /// Kendall rank correlation (tau-b, accounting for ties) between the IDs of the same line
+    KendallTau,
+    /// Earth mover's distance between the distributions of both lists
+    EarthMovers,
+    /// Similarity score (part 2)
+    Similarity,
+    /// Number of IDs in common, counting repeated IDs as many times as they are in both lists
+    Intersection,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 6] = [
+        Metric::L1,
+        Metric::L2,
+        Metric::KendallTau,
+        Metric::EarthMovers,
+        Metric::Similarity,
+        Metric::Intersection,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::L1 => "l1",
+            Metric::L2 => "l2",
+            Metric::KendallTau => "kendall-tau",
+            Metric::EarthMovers => "emd",
+            Metric::Similarity => "similarity",
+            Metric::Intersection => "intersection",
+        }
+    }
+
+    pub fn parse(name: &str) -> Result<Self> {
+        Metric::ALL
+            .into_iter()
+            .find(|metric| metric.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Metric::ALL.iter().map(|m| m.name()).collect();
+                Error::Invalid(format!(
+                    "unknown metric {:?}, expected one of {}",
+                    name,
+                    names.join(", ")
+                ))
+            })
+    }
+
+    /// Compares the lists, in input order as `pairs` or sorted as `lists` (the same lists, sorted
+    /// once for all the metrics), depending on what the metric needs
+    pub fn compute(self, pairs: &Pairs, lists: &Lists) -> Result<Value> {
+        match self {
+            Metric::L1 => p1::solve(lists).map(Value::Integer),
+            Metric::L2 => Ok(Value::Real(l2(lists))),
+            Metric::KendallTau => kendall_tau(pairs).map(Value::Real),
+            Metric::EarthMovers => earth_movers_distance(lists).map(Value::Real),
+            Metric::Similarity => p2::solve(lists).map(Value::Integer),
+            Metric::Intersection => intersection(lists).map(Value::Integer),
+        }
+    }
+}
+
+/// The result of a metric, exact for counts and sums of IDs
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(Answer),
+    Real(f64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Integer(value) => write!(f, "{}", value),
+            Value::Real(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Computes every metric of `metrics` over the input at `path` (`-` for stdin, `None` for the
+/// bundled input), read line by line with `stream`, as one `name: value` line each
+pub fn report(path: Option<&str>, stream: bool, metrics: &[Metric]) -> Result<String> {
+    let pairs: Pairs = if stream {
+        crate::parse_pairs_reader(input::open(path, crate::INPUT)?)?
+    } else {
+        crate::parse_pairs(&input::load(path, crate::INPUT)?)?
+    };
+    let lists: Lists = sorted(pairs.clone());
+    let mut report = String::new();
+    for metric in metrics {
+        report += &format!("{}: {}\n", metric.name(), metric.compute(&pairs, &lists)?);
+    }
+    Ok(report)
+}
+
+/// Square root of the sum of the squared differences between the sorted lists
+pub fn l2(Lists(all_first_nums, all_last_nums): &Lists) -> f64 {
+    all_first_nums
+        .iter()
+        .zip(all_last_nums)
+        .map(|(first_num, last_num)| f64::from(first_num.abs_diff(*last_num)).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Kendall's tau-b between the IDs of the same line, from -1 (one list orders the lines in
+/// reverse of the other) to 1 (both lists order the lines the same way).
+///
+/// Computed in O(n log n) with Knight's algorithm: once the lines are sorted by their first ID,
+/// the discordant pairs of lines are the inversions of the second IDs, counted by a merge sort.
+pub fn kendall_tau(Pairs(all_first_nums, all_last_nums): &Pairs) -> Result<f64> {
+    if all_first_nums.len() != all_last_nums.len() {
+        return Err(Error::Invalid(
+            "Kendall's tau needs lists of the same length".to_string(),
+        ));
+    }
+    let mut lines: Vec<(u32, u32)> = all_first_nums
+        .iter()
+        .copied()
+        .zip(all_last_nums.iter().copied())
+        .collect();
+    lines.sort_unstable();
+
+    let n = lines.len() as u64;
+    let total_pairs: u64 = n * n.saturating_sub(1) / 2;
+    let tied_first: u64 = tied_pairs(&lines, |a, b| a.0 == b.0);
+    let tied_both: u64 = tied_pairs(&lines, |a, b| a == b);
+    let mut last_nums: Vec<u32> = lines.iter().map(|&(_, last_num)| last_num).collect();
+    let discordant: u64 = sort_counting_inversions(&mut last_nums);
+    let tied_last: u64 = tied_pairs(&last_nums, |a, b| a == b);
+
+    let denominator: f64 =
+        ((total_pairs - tied_first) as f64 * (total_pairs - tied_last) as f64).sqrt();
+    if denominator == 0.0 {
+        return Err(Error::Invalid(
+            "Kendall's tau is undefined when all the IDs of a list are equal".to_string(),
+        ));
+    }
+    let numerator = (total_pairs + tied_both) as f64
+        - (tied_first + tied_last) as f64
+        - 2.0 * discordant as f64;
+    Ok(numerator / denominator)
+}
+
+/// Number of pairs of items that are tied, in a slice where tied items are next to each other
+fn tied_pairs<T>(items: &[T], tied: impl Fn(&T, &T) -> bool) -> u64 {
+    items
+        .chunk_by(tied)
+        .map(|run| run.len() as u64 * (run.len() as u64 - 1) / 2)
+        .sum()
+}
+
+/// Sorts `nums` and returns the number of pairs that were in the wrong order. Equal numbers are
+/// not counted.
+fn sort_counting_inversions(nums: &mut [u32]) -> u64 {
+    if nums.len() < 2 {
+        return 0;
+    }
+    let middle: usize = nums.len() / 2;
+    let mut inversions: u64 = sort_counting_inversions(&mut nums[..middle])
+        + sort_counting_inversions(&mut nums[middle..]);
+
+    let (left, right) = nums.split_at(middle);
+    let mut merged: Vec<u32> = Vec::with_capacity(nums.len());
+    let (mut left_index, mut right_index) = (0, 0);
+    while left_index < left.len() && right_index < right.len() {
+        if left[left_index] <= right[right_index] {
+            merged.push(left[left_index]);
+            left_index += 1;
+        } else {
+            // Every remaining number of the left half is greater than this one
+            inversions += (left.len() - left_index) as u64;
+            merged.push(right[right_index]);
+            right_index += 1;
+        }
+    }
+    merged.extend_from_slice(&left[left_index..]);
+    merged.extend_from_slice(&right[right_index..]);
+    nums.copy_from_slice(&merged);
+    inversions
+}
+
+/// The least total distance IDs must be moved by to turn the first list into the second, each list
+/// having a total weight of 1. For lists of the same length, this is the L1 distance divided by the
+/// length.
+///
+/// Computed in one pass over both sorted lists as the area between their cumulative
+/// distributions.
+pub fn earth_movers_distance(Lists(all_first_nums, all_last_nums): &Lists) -> Result<f64> {
+    let (Some(&first_start), Some(&last_start)) = (all_first_nums.first(), all_last_nums.first())
+    else {
+        return Err(Error::Invalid(
+            "the earth mover's distance is undefined for empty lists".to_string(),
+        ));
+    };
+    let (first_len, last_len) = (all_first_nums.len() as f64, all_last_nums.len() as f64);
+    let mut distance: f64 = 0.0;
+    let mut previous: u32 = first_start.min(last_start);
+    let (mut first_index, mut last_index) = (0, 0);
+    loop {
+        let next: u32 = match (
+            all_first_nums.get(first_index),
+            all_last_nums.get(last_index),
+        ) {
+            (Some(&first_num), Some(&last_num)) => first_num.min(last_num),
+            (Some(&num), None) | (None, Some(&num)) => num,
+            (None, None) => return Ok(distance),
+        };
+        // Both cumulative distributions are constant between two consecutive IDs
+        let gap: f64 = (first_index as f64 / first_len - last_index as f64 / last_len).abs();
+        distance += gap * f64::from(next - previous);
+        while all_first_nums.get(first_index) == Some(&next) {
+            first_index += 1;
+        }
+        while all_last_nums.get(last_index) == Some(&next) {
+            last_index += 1;
+        }
+        previous = next;
+    }
+}
+
+/// Size of the multiset intersection of both sorted lists
+pub fn intersection(Lists(all_first_nums, all_last_nums): &Lists) -> Result<Answer> {
+    let mut common: Answer = Answer::from(0u32);
+    let (mut first_index, mut last_index) = (0, 0);
+    while first_index < all_first_nums.len() && last_index < all_last_nums.len() {
+        match all_first_nums[first_index].cmp(&all_last_nums[last_index]) {
+            Ordering::Less => first_index += 1,
+            Ordering::Greater => last_index += 1,
+            Ordering::Equal => {
+                let times_in_first_nums: usize = p2::run_length(&all_first_nums[first_index..]);
+                let times_in_last_nums: usize = p2::run_length(&all_last_nums[last_index..]);
+                let times_in_both: u64 = times_in_first_nums.min(times_in_last_nums) as u64;
+                common = common.try_add(Answer::from(times_in_both))?;
+                first_index += times_in_first_nums;
+                last_index += times_in_last_nums;
+            }
+        }
+    }
+    Ok(common)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_pairs() -> Pairs {
+        crate::parse_pairs(include_str!("input/test.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_metric_names() {
+        for metric in Metric::ALL {
+            assert_eq!(metric, Metric::parse(metric.name()).unwrap());
+        }
+        assert_eq!(
+            "unknown metric \"l3\", expected one of l1, l2, kendall-tau, emd, similarity, intersection",
+            Metric::parse("l3").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_compute() {
+        let pairs: Pairs = test_pairs();
+        let lists: Lists = sorted(pairs.clone());
+        // Sorted, the lists are 1 2 3 3 3 4 and 3 3 3 4 5 9
+        let expected: [Value; 6] = [
+            Value::Integer(Answer::from(11u32)),
+            Value::Real(35f64.sqrt()),
+            Value::Real(-1.0 / 12.0),
+            Value::Real(11.0 / 6.0),
+            Value::Integer(Answer::from(31u32)),
+            Value::Integer(Answer::from(4u32)),
+        ];
+        for (metric, expected) in Metric::ALL.into_iter().zip(expected) {
+            match (metric.compute(&pairs, &lists).unwrap(), expected) {
+                (Value::Real(value), Value::Real(expected)) => {
+                    assert!(
+                        (value - expected).abs() < 1e-12,
+                        "{}: {}",
+                        metric.name(),
+                        value
+                    )
+                }
+                (value, expected) => assert_eq!(expected, value, "{}", metric.name()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let metrics: [Metric; 2] = [Metric::L1, Metric::Similarity];
+        let text: String = report(None, false, &metrics).unwrap();
+        assert_eq!("l1: 1223326\nsimilarity: 21070419\n", text);
+        assert_eq!(text, report(None, true, &metrics).unwrap());
+        assert!(report(Some("/does/not/exist.txt"), false, &metrics).is_err());
+    }
+
+    #[test]
+    fn test_kendall_tau() {
+        let pairs: Pairs = Pairs(vec![12, 2, 1, 12, 2], vec![1, 4, 7, 1, 0]);
+        assert!((kendall_tau(&pairs).unwrap() - -0.47140452079103173).abs() < 1e-12);
+
+        assert_eq!(
+            1.0,
+            kendall_tau(&Pairs(vec![1, 2, 3], vec![4, 5, 6])).unwrap()
+        );
+        assert_eq!(
+            -1.0,
+            kendall_tau(&Pairs(vec![1, 2, 3], vec![6, 5, 4])).unwrap()
+        );
+        assert!(kendall_tau(&Pairs(vec![1, 1], vec![1, 2])).is_err());
+        assert!(kendall_tau(&Pairs::default()).is_err());
+    }
+
+    #[test]
+    fn test_sort_counting_inversions() {
+        let mut nums: Vec<u32> = vec![3, 1, 2, 2, 0];
+        assert_eq!(7, sort_counting_inversions(&mut nums));
+        assert_eq!(vec![0, 1, 2, 2, 3], nums);
+    }
+
+    #[test]
+    fn test_earth_movers_distance() {
+        let lists =
+            |first_nums: Vec<u32>, last_nums: Vec<u32>| sorted(Pairs(first_nums, last_nums));
+        assert_eq!(
+            2.0,
+            earth_movers_distance(&lists(vec![1], vec![3])).unwrap()
+        );
+        // Half of the weight moves from 1 to 3, the other half stays at 3
+        assert_eq!(
+            1.0,
+            earth_movers_distance(&lists(vec![3, 1], vec![3])).unwrap()
+        );
+        let input_lists: Lists = crate::parse(crate::INPUT).unwrap();
+        let l1: f64 = p1::solve(&input_lists)
+            .unwrap()
+            .to_string()
+            .parse()
+            .unwrap();
+        let emd: f64 = earth_movers_distance(&input_lists).unwrap();
+        assert!((emd - l1 / input_lists.first_nums().len() as f64).abs() < 1e-6);
+        assert!(earth_movers_distance(&lists(vec![], vec![1])).is_err());
+    }
+
+    #[test]
+    fn test_intersection() {
+        // Two 1s and one 5 are in both lists
+        assert_eq!(
+            Answer::from(3u32),
+            intersection(&sorted(Pairs(vec![5, 1, 2, 1, 1], vec![1, 5, 3, 1]))).unwrap()
+        );
+        assert_eq!(
+            Answer::from(0u32),
+            intersection(&sorted(Pairs(vec![1, 2], vec![3, 4]))).unwrap()
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::metrics::Metric;
+use aoc_common::{Error, Result};
+
+/// Command line options of the day 1 binaries
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Input path, `-` for stdin, or `None` for the bundled input
+    pub path: Option<String>,
+    /// Read the input line by line instead of loading it all in memory
+    pub stream: bool,
+    /// Metrics to print instead of the answer, in order
+    pub metrics: Vec<Metric>,
+}
+
+impl Options {
+    /// Reads `--stream`, `--metric NAME` and the optional input path. `--metric` can be repeated,
+    /// and `--metric all` selects every metric.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut args = args.into_iter();
+        let mut options = Options::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--stream" => options.stream = true,
+                "--metric" => {
+                    let name: String = args
+                        .next()
+                        .ok_or_else(|| Error::Invalid(format!("missing value for {}", arg)))?;
+                    match name.as_str() {
+                        "all" => options.metrics.extend(Metric::ALL),
+                        _ => options.metrics.push(Metric::parse(&name)?),
+                    }
+                }
+                _ if arg.starts_with("--") => {
+                    return Err(Error::Invalid(format!("unknown option {}", arg)))
+                }
+                _ if options.path.is_none() => options.path = Some(arg),
+                _ => return Err(Error::Invalid(format!("unexpected argument {}", arg))),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Options> {
+        Options::from_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(Options::default(), args(&[]).unwrap());
+        let options = args(&["--stream", "input.txt"]).unwrap();
+        assert_eq!(Some("input.txt".to_string()), options.path);
+        assert!(options.stream);
+        assert!(options.metrics.is_empty());
+        let options = args(&["--metric", "kendall-tau", "--metric", "l1"]).unwrap();
+        assert_eq!(vec![Metric::KendallTau, Metric::L1], options.metrics);
+        assert_eq!(
+            Metric::ALL.to_vec(),
+            args(&["--metric", "all"]).unwrap().metrics
+        );
+        assert!(args(&["--metric", "l3"]).is_err());
+        assert!(args(&["--metric"]).is_err());
+        assert!(args(&["--sort"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::Lists;
+use aoc_common::number::{self, Answer};
+use aoc_common::Result;
+use std::io::BufRead;
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    solve(&crate::parse(input_text)?)
+}
+
+/// Solves the input as it is read, without keeping its text in memory
+pub fn run_reader(reader: impl BufRead) -> Result<Answer> {
+    solve(&crate::parse_reader(reader)?)
+}
+
+pub fn solve(Lists(all_first_nums, all_last_nums): &Lists) -> Result<Answer> {
+    number::sum(
+        all_first_nums
+            .iter()
+            .zip(all_last_nums)
+            .map(|(first_num, last_num)| Answer::from(last_num.abs_diff(*first_num))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(Answer::from(11u32), run(test_text).unwrap());
+    }
+}
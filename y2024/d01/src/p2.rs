@@ -0,0 +1,100 @@
+use crate::Lists;
+use aoc_common::number::{self, Answer, Checked};
+use aoc_common::Result;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub fn run(input_text: &str) -> Result<Answer> {
+    solve(&crate::parse(input_text)?)
+}
+
+/// Solves the input as it is read, without keeping its text in memory
+pub fn run_reader(reader: impl BufRead) -> Result<Answer> {
+    solve(&crate::parse_reader(reader)?)
+}
+
+/// Walks both sorted lists side by side, so that every number is looked at once: O(n + m)
+pub fn solve(Lists(all_first_nums, all_last_nums): &Lists) -> Result<Answer> {
+    let mut similarity_score: Answer = Answer::from(0u32);
+    let (mut first_index, mut last_index) = (0, 0);
+    while first_index < all_first_nums.len() && last_index < all_last_nums.len() {
+        let num: u32 = all_first_nums[first_index];
+        match num.cmp(&all_last_nums[last_index]) {
+            Ordering::Less => first_index += 1,
+            Ordering::Greater => last_index += 1,
+            Ordering::Equal => {
+                let times_in_first_nums: usize = run_length(&all_first_nums[first_index..]);
+                let times_in_last_nums: usize = run_length(&all_last_nums[last_index..]);
+                let addend: Answer = number::product([
+                    Answer::from(num),
+                    Answer::from(times_in_first_nums as u64),
+                    Answer::from(times_in_last_nums as u64),
+                ])?;
+                similarity_score = similarity_score.try_add(addend)?;
+                first_index += times_in_first_nums;
+                last_index += times_in_last_nums;
+            }
+        }
+    }
+
+    Ok(similarity_score)
+}
+
+/// Number of times the first number of a sorted list is repeated at its start
+pub(crate) fn run_length(nums: &[u32]) -> usize {
+    nums.iter().take_while(|&&num| num == nums[0]).count()
+}
+
+/// The former implementation, which scans the whole second list once per distinct number of the
+/// first one: O(n·m). Kept to benchmark [`solve`] against it.
+pub fn solve_quadratic(Lists(all_first_nums, all_last_nums): &Lists) -> Result<Answer> {
+    let mut similarity_score_addends: HashMap<u32, Answer> = HashMap::new();
+    let mut similarity_score: Answer = Answer::from(0u32);
+    for &num in all_first_nums {
+        let addend: &Answer = match similarity_score_addends.entry(num) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let times_num_appears_in_last_nums_list: u64 = all_last_nums
+                    .iter()
+                    .filter(|last_num| **last_num == num)
+                    .collect::<Vec<&u32>>()
+                    .len() as u64;
+                entry.insert(
+                    Answer::from(num).try_mul(Answer::from(times_num_appears_in_last_nums_list))?,
+                )
+            }
+        };
+        similarity_score = similarity_score.try_add(addend.to_owned())?;
+    }
+
+    Ok(similarity_score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pairs;
+
+    #[test]
+    fn test_run() {
+        let test_text: &str = include_str!("input/test.txt");
+        assert_eq!(Answer::from(31u32), run(test_text).unwrap());
+    }
+
+    #[test]
+    fn test_solve_agrees_with_quadratic() {
+        let lists: Lists = crate::parse(crate::INPUT).unwrap();
+        assert_eq!(solve_quadratic(&lists).unwrap(), solve(&lists).unwrap());
+        let empty: Lists = crate::sorted(Pairs::default());
+        assert_eq!(Answer::from(0u32), solve(&empty).unwrap());
+    }
+
+    #[test]
+    fn test_solve_past_u32() {
+        // 70000 × 70000 × 70000 doesn't fit in a u32
+        let lists: Lists = crate::sorted(Pairs(vec![70000; 70000], vec![70000; 70000]));
+        assert_eq!(Answer::from(343_000_000_000_000u64), solve(&lists).unwrap());
+    }
+}
@@ -0,0 +1,106 @@
+//! Checks the solutions against naive reference implementations on random lists
+
+use crate::metrics;
+use crate::{p1, p2, Pairs};
+use aoc_common::number::Answer;
+use proptest::prelude::*;
+use std::cmp::Ordering;
+
+/// Splits every line in two numbers, without sorting the lists
+fn reference_lists(input_text: &str) -> (Vec<u32>, Vec<u32>) {
+    input_text
+        .lines()
+        .map(|line| {
+            let mut numbers = line.split_whitespace().map(|n| n.parse::<u32>().unwrap());
+            (numbers.next().unwrap(), numbers.next().unwrap())
+        })
+        .unzip()
+}
+
+/// Part 1 by definition: pair up the smallest numbers of both lists, then the smallest of the
+/// rest, and so on
+fn reference_p1(input_text: &str) -> u32 {
+    let (mut left, mut right) = reference_lists(input_text);
+    let mut total: u32 = 0;
+    while !left.is_empty() {
+        let smallest_left = left.iter().enumerate().min_by_key(|&(_, n)| n).unwrap().0;
+        let smallest_right = right.iter().enumerate().min_by_key(|&(_, n)| n).unwrap().0;
+        total += left
+            .swap_remove(smallest_left)
+            .abs_diff(right.swap_remove(smallest_right));
+    }
+    total
+}
+
+/// Part 2 by definition: every left number times the number of times it appears on the right
+fn reference_p2(input_text: &str) -> u64 {
+    let (left, right) = reference_lists(input_text);
+    left.iter()
+        .map(|&l| u64::from(l) * right.iter().filter(|&&r| r == l).count() as u64)
+        .sum()
+}
+
+/// Kendall's tau-b by definition, comparing every pair of lines
+fn reference_kendall_tau(Pairs(xs, ys): &Pairs) -> f64 {
+    let n: usize = xs.len();
+    let (mut concordant, mut discordant, mut tied_x, mut tied_y) = (0i64, 0i64, 0, 0);
+    for i in 0..n {
+        for j in i + 1..n {
+            match (xs[i].cmp(&xs[j]), ys[i].cmp(&ys[j])) {
+                (Ordering::Equal, Ordering::Equal) => {
+                    tied_x += 1;
+                    tied_y += 1;
+                }
+                (Ordering::Equal, _) => tied_x += 1,
+                (_, Ordering::Equal) => tied_y += 1,
+                (x, y) if x == y => concordant += 1,
+                _ => discordant += 1,
+            }
+        }
+    }
+    let total = (n * (n - 1) / 2) as f64;
+    (concordant - discordant) as f64 / ((total - tied_x as f64) * (total - tied_y as f64)).sqrt()
+}
+
+/// Small location IDs, so that the lists share many of them
+fn lists() -> impl Strategy<Value = String> {
+    prop::collection::vec((0..100u32, 0..100u32), 1..50).prop_map(|pairs| {
+        pairs
+            .iter()
+            .map(|(left, right)| format!("{left}   {right}"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    })
+}
+
+proptest! {
+    #[test]
+    fn p1_agrees_with_reference(input_text in lists()) {
+        prop_assert_eq!(p1::run(&input_text).unwrap(), Answer::from(reference_p1(&input_text)));
+        prop_assert_eq!(p1::run_reader(input_text.as_bytes()).unwrap(), Answer::from(reference_p1(&input_text)));
+    }
+
+    #[test]
+    fn p2_agrees_with_reference(input_text in lists()) {
+        prop_assert_eq!(p2::run(&input_text).unwrap(), Answer::from(reference_p2(&input_text)));
+        prop_assert_eq!(p2::run_reader(input_text.as_bytes()).unwrap(), Answer::from(reference_p2(&input_text)));
+    }
+
+    #[test]
+    fn kendall_tau_agrees_with_reference(input_text in lists()) {
+        let pairs: Pairs = crate::parse_pairs(&input_text).unwrap();
+        match metrics::kendall_tau(&pairs) {
+            Ok(tau) => prop_assert!((tau - reference_kendall_tau(&pairs)).abs() < 1e-9),
+            // Only when all the IDs of a list are equal
+            Err(_) => prop_assert!(!reference_kendall_tau(&pairs).is_finite()),
+        }
+    }
+
+    #[test]
+    fn earth_movers_distance_is_mean_l1(input_text in lists()) {
+        let lists = crate::parse(&input_text).unwrap();
+        let emd: f64 = metrics::earth_movers_distance(&lists).unwrap();
+        let l1 = reference_p1(&input_text) as f64;
+        prop_assert!((emd - l1 / lists.first_nums().len() as f64).abs() < 1e-9);
+    }
+}